@@ -34,10 +34,12 @@ __author__ = "Scott Griffiths"
 from .field import Field, FieldArray
 from .format import Format, Repeat
 from .bits import Bits
+from .reader import Reader
 from .dtypes import DtypeDefinition, dtype_register, Dtype
 from .bitformat_options import Options
 from .array_ import Array
 from .exceptions import Error, ReadError, InterpretError, ByteAlignError, CreationError
+from .bitstore_helpers import register_literal_prefix
 from typing import List, Tuple, Literal
 
 # The Options class returns a singleton.
@@ -136,6 +138,6 @@ for alias in aliases:
     dtype_register.add_dtype_alias(alias[0], alias[1])
 
 
-__all__ = ['Bits', 'Dtype', 'Format', 'Field', 'Array', 'FieldArray', 'Repeat',
+__all__ = ['Bits', 'Reader', 'Dtype', 'Format', 'Field', 'Array', 'FieldArray', 'Repeat',
            'Error', 'ReadError', 'InterpretError',
-           'ByteAlignError', 'CreationError', 'options']
+           'ByteAlignError', 'CreationError', 'options', 'register_literal_prefix']
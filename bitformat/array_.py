@@ -55,23 +55,29 @@ special_methods = [
     '__add__',
     '__and__',
     '__bool__',
+    '__buffer__',
     '__contains__',
     '__eq__',
+    '__format__',
     '__ge__',
     '__getitem__',
     '__gt__',
+    '__int__',
     '__invert__',
     '__iter__',
     '__le__',
     '__len__',
     '__lshift__',
     '__lt__',
+    '__matmul__',
     '__mul__',
     '__ne__',
     '__or__',
     '__radd__',
     '__rand__',
     '__repr__',
+    '__reversed__',
+    '__rmatmul__',
     '__rmul__',
     '__ror__',
     '__rshift__',
@@ -1,9 +1,15 @@
 from __future__ import annotations
 
+import base64
+import binascii
+import hmac
+import math
 import numbers
 import sys
 import struct
 import io
+import os
+import random
 from collections import abc
 from typing import Union, Iterable, Any, TextIO, overload, Iterator, Type, TypeVar
 import bitformat
@@ -60,11 +66,25 @@ class Bits:
         :rtype: Bits
         """
         d = Dtype(dtype)
+        if d.name in ('u', 'i') and d.bitlength:
+            # Fast path for the most common integer types.
+            x = super().__new__(cls)
+            x._bitstore = BitStore.from_int(value, d.bitlength, d.name == 'i')
+            return x
         return d.build(value)
 
     @classmethod
-    def from_string(cls, s: str, /) -> TBits:
-        """Create a new Bits from a formatted string."""
+    def from_string(cls, s: str, /, strict: bool = False) -> TBits:
+        """Create a new Bits from a formatted string.
+
+        s -- The formatted string, for example '0xff, u8=3'.
+        strict -- If True then raise ValueError for literals that are valid but could be typos:
+                  hex literals with an odd number of digits, and underscores that aren't
+                  between two digits. Defaults to False.
+
+        """
+        if strict:
+            bitstore_helpers.check_literals_strict(s)
         x = super().__new__(cls)
         x._bitstore = bitstore_helpers.str_to_bitstore(s)
         return x
@@ -77,15 +97,116 @@ class Bits:
         return x
 
     @classmethod
-    def join(cls, sequence: Iterable[Any], /) -> TBits:
+    def from_base64(cls, s: str | bytes, /) -> TBits:
+        """Create a new Bits from a base64 encoded string.
+
+        Raises ValueError if s isn't valid base64.
+
+        """
+        try:
+            data = base64.b64decode(s, validate=True)
+        except binascii.Error as e:
+            raise ValueError(f"Cannot create Bits from invalid base64 string: {e}") from None
+        return cls.from_bytes(data)
+
+    @classmethod
+    def from_base32(cls, s: str | bytes, /) -> TBits:
+        """Create a new Bits from a base32 encoded string.
+
+        Raises ValueError if s isn't valid base32.
+
+        """
+        try:
+            data = base64.b32decode(s)
+        except binascii.Error as e:
+            raise ValueError(f"Cannot create Bits from invalid base32 string: {e}") from None
+        return cls.from_bytes(data)
+
+    @classmethod
+    def from_bools(cls, values: Iterable[Any], /) -> TBits:
+        """Create a new Bits from an iterable, with a bit set to 1 for each truthy value.
+
+        >>> Bits.from_bools([True, 0, 'x'])
+        Bits('0b101')
+
+        """
+        x = super().__new__(cls)
+        x._bitstore = BitStore.from_bools(values)
+        return x
+
+    @classmethod
+    def from_float(cls, value: float, length: int, /) -> TBits:
+        """Create a new Bits from a big-endian IEEE 754 floating point number.
+
+        length -- 16, 32 or 64 for half, single or double precision.
+
+        Values too large for the precision become infinities.
+
+        Raises ValueError for any other length.
+
+        >>> Bits.from_float(1.0, 16)
+        Bits('0x3c00')
+
+        """
+        if length not in (16, 32, 64):
+            raise ValueError(f"A length of 16, 32 or 64 is needed to create from a float, but received {length}.")
+        x = super().__new__(cls)
+        x._bitstore = BitStore.from_float(value, length)
+        return x
+
+    @classmethod
+    def from_uint(cls, value: int, length: int, /) -> TBits:
+        """Create a new Bits of exactly length bits from an unsigned integer, stored big-endian.
+
+        Raises ValueError if length <= 0 or if value doesn't fit in length bits.
+
+        >>> Bits.from_uint(10, 8)
+        Bits('0x0a')
+
+        """
+        if length <= 0:
+            raise ValueError(f"A positive length is needed to create from an integer, but received {length}.")
+        x = super().__new__(cls)
+        x._bitstore = BitStore.from_int(value, length, False)
+        return x
+
+    @classmethod
+    def from_int(cls, value: int, length: int, /) -> TBits:
+        """Create a new Bits of exactly length bits from a signed integer, stored big-endian as two's complement.
+
+        Raises ValueError if length <= 0 or if value doesn't fit in length bits.
+
+        >>> Bits.from_int(-1, 12)
+        Bits('0xfff')
+
+        """
+        if length <= 0:
+            raise ValueError(f"A positive length is needed to create from an integer, but received {length}.")
+        x = super().__new__(cls)
+        x._bitstore = BitStore.from_int(value, length, True)
+        return x
+
+    @classmethod
+    def join(cls, sequence: Iterable[Any], /, sep: BitsType | None = None) -> TBits:
         """Return concatenation of Bits.
 
         sequence -- A sequence of Bits.
+        sep -- Optional Bits to insert between each item. Defaults to no separator.
+
+        >>> Bits.join(['0x1', '0x2', '0x3'], sep='0xf')
+        Bits('0x1f2f3')
 
         """
         x = super().__new__(cls)
         x._bitstore = BitStore()
-        for item in sequence:
+        if sep is None:
+            for item in sequence:
+                x._addright(Bits._create_from_bitstype(item))
+            return x
+        sep = Bits._create_from_bitstype(sep)
+        for i, item in enumerate(sequence):
+            if i != 0:
+                x._addright(sep)
             x._addright(Bits._create_from_bitstype(item))
         return x
 
@@ -113,6 +234,138 @@ class Bits:
             return Bits()
         return Dtype('i', length).build(-1)
 
+    @classmethod
+    def mask(cls, length: int, /, set_range: tuple[int, int] | None = None, value: Any = True) -> TBits:
+        """Create a new Bits with a contiguous range of bits set to bool(value) and the rest to the opposite.
+
+        length -- The number of bits.
+        set_range -- A (start, end) tuple giving the bit positions to set. Negative numbers are treated
+                     in the same way as slice indices. Defaults to the whole Bits.
+        value -- If bool(value) is True the range is set to 1 and the rest to 0, otherwise
+                 the range is set to 0 and the rest to 1. Defaults to True.
+
+        Raises ValueError if the range is not valid for the length.
+
+        >>> Bits.mask(8, (2, 5))
+        Bits('0x38')
+
+        """
+        x = cls.zeros(length) if value else cls.ones(length)
+        start, end = (None, None) if set_range is None else set_range
+        start, end = x._validate_slice(start, end)
+        return x.set(value, range(start, end))
+
+    @classmethod
+    def from_random(cls, length: int, /, seed: int | None = None, p: float = 0.5) -> TBits:
+        """Create a new Bits with random contents.
+
+        length -- The number of bits.
+        seed -- A seed for the random number generator, so that results can be reproduced.
+                Defaults to None, which uses a different seed each time.
+        p -- The probability of each bit being 1. Defaults to 0.5.
+
+        Not suitable for cryptographic use.
+
+        Raises ValueError if length < 0 or if p isn't in the range [0, 1].
+
+        """
+        if length < 0:
+            raise ValueError(f"Cannot create a Bits with a negative length of {length}.")
+        if not 0.0 <= p <= 1.0:
+            raise ValueError(f"The probability p must be between 0 and 1, but received {p}.")
+        rng = random.Random(seed)
+        x = super().__new__(cls)
+        if p == 0.5:
+            x._bitstore = BitStore.from_int(rng.getrandbits(length), length, False) if length else BitStore()
+        else:
+            x._bitstore = BitStore.from_bools(rng.random() < p for _ in range(length))
+        return x
+
+    @classmethod
+    def interleave(cls, a: BitsType, b: BitsType, /) -> TBits:
+        """Create a new Bits by alternating the bits of a and b, starting with a.
+
+        The result is a[0], b[0], a[1], b[1], ... and is twice as long as a.
+
+        Raises ValueError if a and b have different lengths.
+
+        >>> Bits.interleave('0b11', '0b00')
+        Bits('0xa')
+
+        """
+        a = Bits._create_from_bitstype(a)
+        b = Bits._create_from_bitstype(b)
+        a._validate_logical_op_lengths(b)
+        x = super().__new__(cls)
+        x._bitstore = BitStore.from_zeros(2 * len(a))
+        x._bitstore.setitem(slice(0, None, 2), a._bitstore)
+        x._bitstore.setitem(slice(1, None, 2), b._bitstore)
+        return x
+
+    @classmethod
+    def all_and(cls, sequence: Iterable[BitsType], /) -> TBits:
+        """Return the bit-wise and of every item in a sequence of equal length Bits.
+
+        Raises ValueError if the sequence is empty or if the lengths differ.
+
+        >>> Bits.all_and(['0b1110', '0b0111', '0b1111'])
+        Bits('0x6')
+
+        """
+        return cls._fold_logical_op(sequence, 'and')
+
+    @classmethod
+    def all_or(cls, sequence: Iterable[BitsType], /) -> TBits:
+        """Return the bit-wise or of every item in a sequence of equal length Bits.
+
+        Raises ValueError if the sequence is empty or if the lengths differ.
+
+        >>> Bits.all_or(['0b1000', '0b0010'])
+        Bits('0xa')
+
+        """
+        return cls._fold_logical_op(sequence, 'or')
+
+    @classmethod
+    def _fold_logical_op(cls, sequence: Iterable[BitsType], op: str) -> TBits:
+        """Accumulate a bit-wise logical operation across a sequence into a single store."""
+        it = iter(sequence)
+        try:
+            first = Bits._create_from_bitstype(next(it))
+        except StopIteration:
+            raise ValueError(f"Cannot fold a logical {op} over an empty sequence.") from None
+        x = super().__new__(cls)
+        x._bitstore = first._bitstore._copy()
+        for item in it:
+            bs = Bits._create_from_bitstype(item)
+            x._validate_logical_op_lengths(bs)
+            if op == 'and':
+                x._bitstore &= bs._bitstore
+            else:
+                x._bitstore |= bs._bitstore
+        return x
+
+    @classmethod
+    def from_positions(cls, positions: Iterable[int], length: int, /) -> TBits:
+        """Create a new Bits with only the given bit positions set to one.
+
+        positions -- An iterable of the bit positions to set.
+        length -- The number of bits.
+
+        Raises ValueError if any position is not in the range [0, length).
+
+        >>> Bits.from_positions([0, 3], 4)
+        Bits('0x9')
+
+        """
+        x = super().__new__(cls)
+        x._bitstore = BitStore.from_zeros(length)
+        for p in positions:
+            if not 0 <= p < length:
+                raise ValueError(f"Bit position {p} out of range for Bits of length {length}.")
+            x._bitstore.setitem(p, 1)
+        return x
+
     def parse(self, dtype: Dtype | str, /) -> Any:
         """Interpret the Bits as a given data type."""
         d = Dtype(dtype)
@@ -141,6 +394,11 @@ class Bits:
         """Iterate over the bits."""
         return iter(self._bitstore)
 
+    def __reversed__(self) -> Iterable[bool]:
+        """Iterate over the bits in reverse order, from the final bit to the first."""
+        for i in range(len(self) - 1, -1, -1):
+            yield bool(self._bitstore.getindex(i))
+
     def __copy__(self: TBits) -> TBits:
         """Return a new copy of the Bits for the copy module.
 
@@ -166,6 +424,23 @@ class Bits:
         # Bits can't really be ordered.
         return NotImplemented
 
+    def compare(self, other: BitsType, /) -> int:
+        """Return -1, 0 or 1 depending on the lexicographic bit order of self and other.
+
+        Bits are compared from the start, and if one is a prefix of the other then the
+        shorter one is ordered first. Can be used with functools.cmp_to_key for sorting.
+
+        other -- The Bits to compare with.
+
+        >>> Bits('0b011').compare('0b1')
+        -1
+
+        """
+        other = Bits._create_from_bitstype(other)
+        a = self._bitstore.slice_to_bin()
+        b = other._bitstore.slice_to_bin()
+        return (a > b) - (a < b)
+
     def __add__(self: TBits, bs: BitsType, /) -> TBits:
         """Concatenate Bits and return a new Bits."""
         bs = self.__class__._create_from_bitstype(bs)
@@ -178,6 +453,28 @@ class Bits:
         bs = self.__class__._create_from_bitstype(bs)
         return bs.__add__(self)
 
+    def concat(self: TBits, *others: BitsType) -> TBits:
+        """Return new Bits with each of the others concatenated onto the end.
+
+        This avoids the intermediate objects created by chaining '+'.
+
+        >>> Bits('0x1').concat('0x2', b'\\x34', '0b1')
+        Bits('0b00010010001101001')
+
+        """
+        s = self._copy()
+        for bs in others:
+            s._addright(Bits._create_from_bitstype(bs))
+        return s
+
+    def __matmul__(self: TBits, bs: BitsType, /) -> TBits:
+        """Concatenate Bits and return a new Bits. The same as using '+'."""
+        return self.__add__(bs)
+
+    def __rmatmul__(self: TBits, bs: BitsType, /) -> TBits:
+        """Concatenate Bits and return a new Bits. The same as using '+'."""
+        return self.__radd__(bs)
+
     @overload
     def __getitem__(self: TBits, key: slice, /) -> TBits:
         ...
@@ -186,15 +483,52 @@ class Bits:
     def __getitem__(self, key: int, /) -> bool:
         ...
 
-    def __getitem__(self: TBits, key: Union[slice, int], /) -> Union[TBits, bool]:
+    @overload
+    def __getitem__(self: TBits, key: Bits, /) -> TBits:
+        ...
+
+    def __getitem__(self: TBits, key: Union[slice, int, Bits], /) -> Union[TBits, bool]:
         """Return a new Bits representing a slice of the current Bits.
+
+        If key is a Bits of the same length then it's used as a mask, and the new Bits
+        contains only the bits where the mask is 1.
         """
         if isinstance(key, numbers.Integral):
-            return bool(self._bitstore.getindex(key))
+            try:
+                return bool(self._bitstore.getindex(key))
+            except IndexError:
+                raise IndexError(f"Bit position {key} out of range for Bits of length {len(self)}.") from None
         bs = super().__new__(self.__class__)
+        if isinstance(key, Bits):
+            if len(key) != len(self):
+                raise ValueError(f"A mask used as an index must have the same length as the Bits. "
+                                 f"Received a mask of length {len(key)} for Bits of length {len(self)}.")
+            bs._bitstore = self._bitstore.getmasked(key._bitstore)
+            return bs
         bs._bitstore = self._bitstore.getslice_withstep(key)
         return bs
 
+    def strided(self: TBits, start: int | None, stop: int | None, step: int, /) -> TBits:
+        """Return a new Bits made from every step-th bit of a slice.
+
+        Equivalent to self[start:stop:step], with the same handling of None and negative values.
+
+        start -- The first bit position to use.
+        stop -- One past the final bit position to use.
+        step -- The step between bit positions. Can be negative, but not zero.
+
+        Raises ValueError if step is zero.
+
+        >>> Bits('0b10101010').strided(0, None, 2)
+        Bits('0xf')
+
+        """
+        if step == 0:
+            raise ValueError("The step for a strided slice cannot be zero.")
+        bs = super().__new__(self.__class__)
+        bs._bitstore = self._bitstore.getslice_withstep(slice(start, stop, step))
+        return bs
+
     def __len__(self) -> int:
         """Return the length of the Bits in bits."""
         return len(self._bitstore)
@@ -202,6 +536,21 @@ class Bits:
     def __bytes__(self) -> bytes:
         return self.to_bytes()
 
+    def __buffer__(self, flags: int, /) -> memoryview:
+        """Return a read-only memoryview of the bytes, for the buffer protocol (Python 3.12+).
+
+        The view is of a copy of the data, so it stays valid even if the Bits is deleted.
+        This is used by memoryview() and bytearray(), which will raise BufferError for a Bits
+        that isn't byte aligned, such as Bits('0x123'). Use bytes() or to_bytes() to pad the
+        final byte instead.
+
+        Raises BufferError if the Bits is not a whole number of bytes long.
+
+        """
+        if len(self) % 8 != 0:
+            raise BufferError(f"Cannot create a buffer from Bits of length {len(self)} as it is not a multiple of 8 bits.")
+        return memoryview(self._bitstore.to_bytes())
+
     def __str__(self) -> str:
         """Return string representations of Bits for printing.
 
@@ -238,6 +587,33 @@ class Bits:
             return f'0x{hex_with_underscores}, 0b{bin_at_end}'
         return '\n'.join(interpretations)
 
+    def __format__(self, format_spec: str, /) -> str:
+        """Return the Bits formatted according to format_spec.
+
+        format_spec -- 'b' for binary, 'o' for octal, 'x' or 'X' for lower or upper case hex.
+                       An empty format_spec gives the same as str().
+
+        Raises ValueError if the format_spec is unknown, or if the length isn't a multiple
+        of 3 for octal or 4 for hex.
+
+        >>> f"{Bits('0xab'):x}"
+        'ab'
+
+        """
+        if format_spec == '':
+            return str(self)
+        if format_spec == 'b':
+            return self._getbin()
+        if format_spec == 'o':
+            if len(self) % 3 != 0:
+                raise ValueError(f"Cannot format Bits of length {len(self)} as octal as it's not a multiple of 3.")
+            return self._getoct()
+        if format_spec in ('x', 'X'):
+            if len(self) % 4 != 0:
+                raise ValueError(f"Cannot format Bits of length {len(self)} as hex as it's not a multiple of 4.")
+            return self.to_hex(upper=format_spec == 'X')
+        raise ValueError(f"Unknown format specification '{format_spec}' for Bits. Use 'b', 'o', 'x' or 'X'.")
+
     def _repr(self, classname: str, length: int):
         if length == 0:
             s = ''
@@ -258,14 +634,50 @@ class Bits:
     def __eq__(self, bs: Any, /) -> bool:
         """Return True if two Bits have the same binary representation.
 
+        Returns NotImplemented if bs can't be converted to a Bits, so that Python
+        can try the comparison the other way round.
+
         >>> Bits('0b1110') == '0xe'
         True
 
         """
+        if isinstance(bs, Bits):
+            # No conversion needed, and differing lengths can't be equal.
+            return len(self) == len(bs) and self._bitstore == bs._bitstore
         try:
             return self._bitstore == Bits._create_from_bitstype(bs)._bitstore
         except TypeError:
+            return NotImplemented
+
+    def constant_time_eq(self, other: BitsType, /) -> bool:
+        """Return True if the two Bits are equal, comparing their contents in constant time.
+
+        Unlike ==, the time taken doesn't depend on where the first difference occurs, which
+        makes it suitable for comparing secret values such as message authentication codes.
+        The lengths are not treated as secret, so Bits of different lengths return False at once.
+
+        """
+        other = Bits._create_from_bitstype(other)
+        if len(self) != len(other):
             return False
+        return hmac.compare_digest(self._bitstore.to_bytes(), other._bitstore.to_bytes())
+
+    def equals_float(self, value: float, /, rel_tol: float = 0.0) -> bool:
+        """Return whether the Bits interpreted as a float is equal to value.
+
+        The Bits must be 16, 32 or 64 bits long and is interpreted as a big-endian IEEE float.
+
+        value -- The float to compare with.
+        rel_tol -- The relative tolerance allowed, as used by math.isclose. Defaults to 0.0,
+                   which requires an exact match.
+
+        Note that as with ordinary floats a NaN is never equal to anything, including another NaN.
+
+        >>> Bits('f32=0.1').equals_float(0.1, rel_tol=1e-6)
+        True
+
+        """
+        return math.isclose(self.parse('f'), value, rel_tol=rel_tol)
 
     def __ne__(self, bs: Any, /) -> bool:
         """Return False if two Bits have the same binary representation.
@@ -274,7 +686,8 @@ class Bits:
         False
 
         """
-        return not self.__eq__(bs)
+        eq = self.__eq__(bs)
+        return eq if eq is NotImplemented else not eq
 
     def __invert__(self: TBits) -> TBits:
         """Return the Bits with every bit inverted.
@@ -320,6 +733,33 @@ class Bits:
         s._addright(self._slice(0, len(self) - n))
         return s
 
+    def shift(self: TBits, n: int, /, mode: str = 'logical') -> TBits:
+        """Return Bits shifted by n, to the left if n > 0 and to the right if n < 0.
+
+        n -- The number of bits to shift by.
+        mode -- How to fill the vacated bits:
+                'logical' fills with zeros, the same as the << and >> operators.
+                'arithmetic' copies the most significant bit on right shifts, preserving the sign.
+                'circular' wraps bits around from the other end, the same as rol and ror.
+                Defaults to 'logical'.
+
+        Raises ValueError if mode is unknown, or if the Bits is empty and mode isn't 'circular'.
+
+        >>> Bits('0b10010000').shift(-2, mode='arithmetic')
+        Bits('0xe4')
+
+        """
+        if mode == 'circular':
+            return self.rol(n) if n >= 0 else self.ror(-n)
+        if mode not in ('logical', 'arithmetic'):
+            raise ValueError(f"Unknown shift mode '{mode}'. Must be 'logical', 'arithmetic' or 'circular'.")
+        if n >= 0:
+            return self << n
+        s = self >> -n
+        if mode == 'arithmetic' and self[0]:
+            s = s.one_range(0, min(-n, len(self)))
+        return s
+
     def __mul__(self: TBits, n: int, /) -> TBits:
         """Return new Bits consisting of n concatenations of self.
 
@@ -331,10 +771,30 @@ class Bits:
             raise ValueError("Cannot multiply by a negative integer.")
         if n == 0:
             return self.__class__()
-        s = self._copy()
-        s._imul(n)
+        s = object.__new__(self.__class__)
+        s._bitstore = self._bitstore * n
         return s
 
+    def repeat_to_length(self: TBits, length: int, /) -> TBits:
+        """Return new Bits made by repeating self until it is exactly length bits long.
+
+        The final repetition is truncated if needed.
+
+        length -- The length in bits of the new Bits.
+
+        Raises ValueError if self is empty or if length < 0.
+
+        >>> Bits('0b110').repeat_to_length(8)
+        Bits('0xdb')
+
+        """
+        if len(self) == 0:
+            raise ValueError("Cannot repeat an empty Bits to a length.")
+        if length < 0:
+            raise ValueError(f"length must be >= 0, but received {length}.")
+        s = self * -(-length // len(self))
+        return s._slice(0, length)
+
     def __rmul__(self: TBits, n: int, /) -> TBits:
         """Return Bits consisting of n concatenations of self.
 
@@ -353,6 +813,7 @@ class Bits:
         if bs is self:
             return self
         bs = Bits._create_from_bitstype(bs)
+        self._validate_logical_op_lengths(bs)
         s = object.__new__(self.__class__)
         s._bitstore = self._bitstore & bs._bitstore
         return s
@@ -374,6 +835,7 @@ class Bits:
         if bs is self:
             return self
         bs = Bits._create_from_bitstype(bs)
+        self._validate_logical_op_lengths(bs)
         s = object.__new__(self.__class__)
         s._bitstore = self._bitstore | bs._bitstore
         return s
@@ -393,6 +855,7 @@ class Bits:
 
         """
         bs = Bits._create_from_bitstype(bs)
+        self._validate_logical_op_lengths(bs)
         s = object.__new__(self.__class__)
         s._bitstore = self._bitstore ^ bs._bitstore
         return s
@@ -405,12 +868,70 @@ class Bits:
         """
         return self.__xor__(bs)
 
+    def majority(self: TBits, others: Iterable[BitsType], /) -> TBits:
+        """Return new Bits where each bit is the majority vote of self and others.
+
+        For each bit position the result is 1 if more than half of all the inputs (including self)
+        have a 1 at that position. With an even number of inputs a tied vote gives a 0.
+
+        others -- An iterable of Bits, each the same length as self.
+
+        Raises ValueError if the Bits have differing lengths.
+
+        >>> Bits('0b1100').majority(['0b1010', '0b0110'])
+        Bits('0xe')
+
+        """
+        inputs = [self._bitstore]
+        for bs in others:
+            bs = Bits._create_from_bitstype(bs)
+            self._validate_logical_op_lengths(bs)
+            inputs.append(bs._bitstore)
+        s = object.__new__(self.__class__)
+        if len(inputs) == 1:
+            s._bitstore = self._bitstore._copy()
+            return s
+        if len(inputs) == 3:
+            a, b, c = inputs
+            s._bitstore = (a & b) | (a & c) | (b & c)
+            return s
+        # Keep a bit-sliced count of the 1s at every position, so planes[i] holds bit i of each count.
+        planes = []
+        for carry in inputs:
+            for plane in planes:
+                next_carry = plane & carry
+                plane ^= carry
+                carry = next_carry
+                if not carry.any_set():
+                    break
+            else:
+                planes.append(carry._copy())
+        # Then compare each count against the threshold, working down from the most significant bit.
+        threshold = len(inputs) // 2
+        greater = BitStore.from_zeros(len(self))
+        if threshold >> len(planes) == 0:
+            equal = BitStore.from_zeros(len(self))
+            equal.invert()
+            for i in reversed(range(len(planes))):
+                if (threshold >> i) & 1:
+                    equal &= planes[i]
+                else:
+                    greater |= equal & planes[i]
+                    not_plane = planes[i]._copy()
+                    not_plane.invert()
+                    equal &= not_plane
+        s._bitstore = greater
+        return s
+
     def __contains__(self, bs: BitsType, /) -> bool:
         """Return whether bs is contained in the current Bits.
 
-        bs -- The Bits to search for.
+        bs -- The Bits to search for. An empty Bits is always contained.
 
         """
+        bs = Bits._create_from_bitstype(bs)
+        if len(bs) == 0:
+            return True
         found = Bits.find(self, bs, bytealigned=False)
         return False if found is None else True
 
@@ -432,6 +953,14 @@ class Bits:
         """Return False if Bits is empty, otherwise return True."""
         return len(self) != 0
 
+    def __int__(self) -> int:
+        """Return the Bits interpreted as a big-endian unsigned integer.
+
+        Raises ValueError if the Bits is empty.
+
+        """
+        return self._getuint()
+
     def _setbits(self, bs: BitsType, length: None = None) -> None:
         bs = Bits._create_from_bitstype(bs)
         self._bitstore = bs._bitstore
@@ -443,7 +972,7 @@ class Bits:
     def _getbytes(self) -> bytes:
         """Return the data as an ordinary bytes object."""
         if len(self) % 8:
-            raise bitformat.InterpretError("Cannot interpret as bytes unambiguously - not multiple of 8 bits.")
+            raise bitformat.InterpretError(f"Cannot interpret as bytes unambiguously - length of {len(self)} is not a multiple of 8 bits.")
         return self._bitstore.to_bytes()
 
     _unprintable = list(range(0x00, 0x20))  # ASCII control characters
@@ -581,20 +1110,6 @@ class Bits:
         """Invert every bit."""
         self._bitstore.invert()
 
-    def _imul(self: TBits, n: int, /) -> TBits:
-        """Concatenate n copies of self in place. Return self."""
-        assert n >= 0
-        if n == 0:
-            self._clear()
-        else:
-            m = 1
-            old_len = len(self)
-            while m * 2 < n:
-                self._addright(self)
-                m *= 2
-            self._addright(self[0:(n - m) * old_len])
-        return self
-
     def _getbits(self: TBits):
         return self._copy()
 
@@ -606,6 +1121,12 @@ class Bits:
             raise ValueError(f"Invalid slice positions for Bits length {len(self)}: start={start}, end={end}.")
         return start, end
 
+    def _validate_logical_op_lengths(self, bs: Bits) -> None:
+        """Raise ValueError if bs can't be used with self in a bit-wise logical operation."""
+        if len(self) != len(bs):
+            raise ValueError(f"For logical operations the lengths of both Bits must match. "
+                             f"Received lengths of {len(self)} and {len(bs)} bits.")
+
     def find(self, bs: BitsType, /, start: int | None = None, end: int | None = None,
              bytealigned: bool | None = None) -> int | None:
         """Find first occurrence of substring bs.
@@ -614,12 +1135,13 @@ class Bits:
 
         bs -- The Bits to find.
         start -- The bit position to start the search. Defaults to 0.
-        end -- The bit position one past the last bit to search.
-               Defaults to len(self).
+        end -- The bit position one past the last bit to search. Only matches that
+               finish at or before end are found. Defaults to len(self), and values
+               larger than len(self) are treated as len(self).
         bytealigned -- If True the Bits will only be
                        found on byte boundaries.
 
-        Raises ValueError if bs is empty, if start < 0, if end > len(self) or
+        Raises ValueError if bs is empty, if start is out of range or
         if end < start.
 
         >>> Bits.from_string('0xc3e').find('0b1111')
@@ -629,11 +1151,78 @@ class Bits:
         bs = Bits._create_from_bitstype(bs)
         if len(bs) == 0:
             raise ValueError("Cannot find an empty Bits.")
+        if end is not None and end > len(self):
+            end = len(self)
         start, end = self._validate_slice(start, end)
         ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
         p = self._bitstore.find(bs._bitstore, start, end, ba)
         return None if p == -1 else p
 
+    def find_all_positions(self, bs: BitsType, /, start: int | None = None, end: int | None = None,
+                           bytealigned: bool | None = None) -> list[int]:
+        """Return a list of the bit positions of all occurrences of bs.
+
+        This gives the same positions as find_all, including overlapping occurrences, but
+        is faster when all of them are needed.
+
+        bs -- The Bits to find.
+        start -- The bit position to start the search. Defaults to 0.
+        end -- The bit position one past the last bit to search. Defaults to len(self),
+               and values larger than len(self) are treated as len(self), as for find.
+        bytealigned -- If True the Bits will only be found on byte boundaries.
+
+        Raises ValueError if bs is empty, if start is out of range or
+        if end < start.
+
+        >>> Bits('0b10101').find_all_positions('0b101')
+        [0, 2]
+
+        """
+        bs = Bits._create_from_bitstype(bs)
+        if len(bs) == 0:
+            raise ValueError("Cannot find an empty Bits.")
+        if end is not None and end > len(self):
+            end = len(self)
+        start, end = self._validate_slice(start, end)
+        ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
+        return self._bitstore.findall_list(bs._bitstore, start, end, ba)
+
+    def find_between(self, start_marker: BitsType, end_marker: BitsType, /,
+                     bytealigned: bool | None = None) -> Iterator[Bits]:
+        """Return Bits generator of the bits between each pair of start and end markers.
+
+        The search alternates between the two markers, so after each start marker the next
+        end marker is found, and the search for the next start marker continues after that.
+        The markers themselves aren't included in the output, and a start marker without
+        a following end marker is ignored.
+
+        start_marker -- The Bits that marks the start of each section.
+        end_marker -- The Bits that marks the end of each section.
+        bytealigned -- If True the markers will only be found on byte boundaries.
+
+        Raises ValueError if either marker is empty.
+
+        >>> list(Bits('0x0a12b0a3b').find_between('0xa', '0xb'))
+        [Bits('0x12'), Bits('0x3')]
+
+        """
+        start_marker = Bits._create_from_bitstype(start_marker)
+        end_marker = Bits._create_from_bitstype(end_marker)
+        if len(start_marker) == 0 or len(end_marker) == 0:
+            raise ValueError("Cannot find between empty markers.")
+        ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
+        pos = 0
+        while True:
+            p = self._bitstore.find(start_marker._bitstore, pos, len(self), ba)
+            if p == -1:
+                return
+            section_start = p + len(start_marker)
+            q = self._bitstore.find(end_marker._bitstore, section_start, len(self), ba)
+            if q == -1:
+                return
+            yield self._slice(section_start, q)
+            pos = q + len(end_marker)
+
     def find_all(self, bs: BitsType, start: int | None = None, end: int | None = None, count: int | None = None,
                  bytealigned: bool | None = None) -> Iterable[int]:
         """Find all occurrences of bs. Return generator of bit positions.
@@ -659,43 +1248,246 @@ class Bits:
         ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
         return self._findall(bs, start, end, count, ba)
 
-    def _findall(self, bs: Bits, start: int, end: int, count: int | None,
-                 bytealigned: bool) -> Iterable[int]:
-        c = 0
-        for i in self._bitstore.findall(bs._bitstore, start, end, bytealigned):
-            if count is not None and c >= count:
-                return
-            c += 1
-            yield i
-        return
+    def occurrences(self, bs: BitsType, /, bytealigned: bool | None = None) -> int:
+        """Return the number of non-overlapping occurrences of bs.
 
-    def rfind(self, bs: BitsType, /, start: int | None = None, end: int | None = None,
-              bytealigned: bool | None = None) -> int | None:
-        """Find final occurrence of substring bs.
+        Occurrences are counted from the start, and the search continues from the end of each match.
 
-        Returns a the bit position if found, or None if not found.
+        bs -- The Bits to count.
+        bytealigned -- If True the Bits will only be found on byte boundaries.
 
-        bs -- The Bits to find.
-        start -- The bit position to end the reverse search. Defaults to 0.
-        end -- The bit position one past the first bit to reverse search.
-               Defaults to len(self).
-        bytealigned -- If True the Bits will only be found on byte
-                       boundaries.
+        Raises ValueError if bs is empty.
 
-        Raises ValueError if bs is empty, if start < 0, if end > len(self) or
-        if end < start.
+        >>> Bits('0b11111').occurrences('0b11')
+        2
 
         """
         bs = Bits._create_from_bitstype(bs)
-        start, end = self._validate_slice(start, end)
-        ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
         if len(bs) == 0:
-            raise ValueError("Cannot find an empty Bits.")
-        p = self._bitstore.rfind(bs._bitstore, start, end, ba)
-        return None if p == -1 else p
+            raise ValueError("Cannot count occurrences of an empty Bits.")
+        ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
+        return sum(1 for _ in self._findall_non_overlapping(bs, 0, len(self), ba))
+
+    def count_occurrences(self, bs: BitsType, /, bytealigned: bool | None = None, overlapping: bool = False) -> int:
+        """Return the number of occurrences of bs.
+
+        bs -- The Bits to count.
+        bytealigned -- If True the Bits will only be found on byte boundaries.
+        overlapping -- If True then overlapping occurrences are all counted, otherwise each search
+                       continues from the end of the previous match. Defaults to False.
+
+        Raises ValueError if bs is empty.
+
+        >>> Bits('0b1111').count_occurrences('0b11', overlapping=True)
+        3
+
+        """
+        if not overlapping:
+            return self.occurrences(bs, bytealigned=bytealigned)
+        bs = Bits._create_from_bitstype(bs)
+        if len(bs) == 0:
+            raise ValueError("Cannot count occurrences of an empty Bits.")
+        return sum(1 for _ in self.find_all(bs, bytealigned=bytealigned))
+
+    def _findall_non_overlapping(self, bs: Bits, start: int, end: int, bytealigned: bool) -> Iterator[int]:
+        """Yield positions of bs, continuing each search from the end of the previous match."""
+        next_start = start
+        for p in self._bitstore.findall(bs._bitstore, start, end, bytealigned):
+            if p >= next_start:
+                yield p
+                next_start = p + len(bs)
+
+    def split(self, delimiter: BitsType, /, bytealigned: bool | None = None,
+              count: int | None = None) -> Iterator[Bits]:
+        """Return Bits generator by splitting at occurrences of delimiter.
+
+        The delimiter isn't included in the output, and occurrences are found from the start
+        so can't overlap. The final item generated is whatever follows the last delimiter.
+
+        delimiter -- The Bits to split at.
+        bytealigned -- If True the delimiter will only be found on byte boundaries.
+        count -- If specified then at most count items are generated.
+                 Default is to split as many times as possible.
+
+        Raises ValueError if delimiter is empty or count < 0.
+
+        >>> list(Bits('0b1101011').split('0b0'))
+        [Bits('0b11'), Bits('0b1'), Bits('0b11')]
+
+        """
+        delimiter = Bits._create_from_bitstype(delimiter)
+        if len(delimiter) == 0:
+            raise ValueError("Cannot split with an empty delimiter.")
+        if count is not None and count < 0:
+            raise ValueError(f"Cannot split - count must be >= 0, but received {count}.")
+        ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
+        c = 0
+        segment_start = 0
+        for p in self._findall_non_overlapping(delimiter, 0, len(self), ba):
+            if count is not None and c >= count:
+                return
+            c += 1
+            yield self._slice(segment_start, p)
+            segment_start = p + len(delimiter)
+        if count is None or c < count:
+            yield self._slice(segment_start, len(self))
+
+    def split_where(self, delimiter: BitsType, /, keep_delimiter: bool = False,
+                    bytealigned: bool | None = None) -> list[Bits]:
+        """Return a list of Bits found by splitting at every occurrence of delimiter.
+
+        Occurrences of delimiter are found from the start and can't overlap.
+
+        delimiter -- The Bits to split at.
+        keep_delimiter -- If True each delimiter is kept at the end of the segment before it,
+                          which suits terminated records. In this case an empty final
+                          segment is not included. Defaults to False.
+        bytealigned -- If True the delimiter will only be found on byte boundaries.
+
+        Raises ValueError if delimiter is empty.
+
+        >>> Bits('0b1101011').split_where('0b0')
+        [Bits('0b11'), Bits('0b1'), Bits('0b11')]
+
+        """
+        delimiter = Bits._create_from_bitstype(delimiter)
+        if len(delimiter) == 0:
+            raise ValueError("Cannot split with an empty delimiter.")
+        ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
+        segments = []
+        segment_start = 0
+        for p in self._findall_non_overlapping(delimiter, 0, len(self), ba):
+            segment_end = p + len(delimiter) if keep_delimiter else p
+            segments.append(self._slice(segment_start, segment_end))
+            segment_start = p + len(delimiter)
+        if not keep_delimiter or segment_start != len(self):
+            segments.append(self._slice(segment_start, len(self)))
+        return segments
+
+    def _findall(self, bs: Bits, start: int, end: int, count: int | None,
+                 bytealigned: bool) -> Iterable[int]:
+        c = 0
+        for i in self._bitstore.findall(bs._bitstore, start, end, bytealigned):
+            if count is not None and c >= count:
+                return
+            c += 1
+            yield i
+        return
+
+    def longest_common_substring(self, other: BitsType, /) -> tuple[int, int, int]:
+        """Find the longest run of bits that appears in both self and other.
+
+        Returns a tuple of (start position in self, start position in other, length).
+        If there are several matches of the same length the first one found in other is used.
+        If there is no common bit then (0, 0, 0) is returned.
+
+        other -- The Bits to compare with.
+
+        The length is found with a binary search. Each candidate length is tested with a rolling
+        hash over every window of that length, and hash matches are checked against the actual
+        bits, so the time taken is O((m + n) * log(k)) with O(m) extra memory, where m and n are
+        the lengths of self and other and k is the length of the shorter one.
+
+        >>> Bits('0b0011101').longest_common_substring('0b1111')
+        (2, 0, 3)
+
+        """
+        other = Bits._create_from_bitstype(other)
+        a = self._bitstore.tolist()
+        b = other._bitstore.tolist()
+        modulus = (1 << 61) - 1
+
+        def window_hashes(bits: list[bool], length: int) -> Iterator[tuple[int, int]]:
+            """Yield (start position, hash) for every window of the given length."""
+            top = pow(2, length - 1, modulus)
+            h = 0
+            for p in range(length):
+                h = (2 * h + bits[p]) % modulus
+            yield 0, h
+            for p in range(length, len(bits)):
+                h = (2 * (h - bits[p - length] * top) + bits[p]) % modulus
+                yield p - length + 1, h
+
+        def find_match(length: int) -> tuple[int, int] | None:
+            seen: dict[int, list[int]] = {}
+            for i, h in window_hashes(a, length):
+                seen.setdefault(h, []).append(i)
+            for j, h in window_hashes(b, length):
+                for i in seen.get(h, ()):
+                    # Check the bits themselves to rule out a hash collision.
+                    if self._bitstore.getslice(i, i + length) == other._bitstore.getslice(j, j + length):
+                        return i, j
+            return None
+
+        best = (0, 0, 0)
+        lo, hi = 1, min(len(a), len(b))
+        while lo <= hi:
+            mid = (lo + hi) // 2
+            m = find_match(mid)
+            if m is None:
+                hi = mid - 1
+            else:
+                best = (m[0], m[1], mid)
+                lo = mid + 1
+        return best
+
+    def rfind(self, bs: BitsType, /, start: int | None = None, end: int | None = None,
+              bytealigned: bool | None = None) -> int | None:
+        """Find final occurrence of substring bs.
+
+        Returns a the bit position if found, or None if not found.
+
+        bs -- The Bits to find.
+        start -- The bit position to end the reverse search. Defaults to 0.
+        end -- The bit position one past the first bit to reverse search.
+               Defaults to len(self).
+        bytealigned -- If True the Bits will only be found on byte
+                       boundaries.
+
+        Raises ValueError if bs is empty, if start < 0, if end > len(self) or
+        if end < start.
+
+        """
+        bs = Bits._create_from_bitstype(bs)
+        start, end = self._validate_slice(start, end)
+        ba = bitformat.options.bytealigned if bytealigned is None else bytealigned
+        if len(bs) == 0:
+            raise ValueError("Cannot find an empty Bits.")
+        p = self._bitstore.rfind(bs._bitstore, start, end, ba)
+        return None if p == -1 else p
+
+    def index(self, bs: BitsType, /, start: int | None = None, end: int | None = None,
+              bytealigned: bool | None = None) -> int:
+        """Find first occurrence of substring bs, raising ValueError if it isn't found.
+
+        The parameters are the same as for find().
+
+        Raises ValueError if bs is not found, if bs is empty, if start is out of range or if end < start.
+
+        """
+        bs = Bits._create_from_bitstype(bs)
+        p = self.find(bs, start, end, bytealigned)
+        if p is None:
+            raise ValueError(f"{bs!r} not found in Bits of length {len(self)}.")
+        return p
+
+    def rindex(self, bs: BitsType, /, start: int | None = None, end: int | None = None,
+               bytealigned: bool | None = None) -> int:
+        """Find final occurrence of substring bs, raising ValueError if it isn't found.
+
+        The parameters are the same as for rfind().
+
+        Raises ValueError if bs is not found, if bs is empty, if start < 0, if end > len(self) or if end < start.
+
+        """
+        bs = Bits._create_from_bitstype(bs)
+        p = self.rfind(bs, start, end, bytealigned)
+        if p is None:
+            raise ValueError(f"{bs!r} not found in Bits of length {len(self)}.")
+        return p
 
     def cut(self, bits: int, start: int | None = None, end: int | None = None,
-            count: int | None = None) -> Iterator[Bits]:
+            count: int | None = None, exact: bool = False) -> Iterator[Bits]:
         """Return Bits generator by cutting into bits sized chunks.
 
         bits -- The size in bits of the Bits chunks to generate.
@@ -704,6 +1496,9 @@ class Bits:
                Defaults to len(self).
         count -- If specified then at most count items are generated.
                  Default is to cut as many times as possible.
+        exact -- If True then every chunk must be exactly bits long, and a ValueError is
+                 raised if end - start isn't a multiple of bits. Defaults to False, in which
+                 case the final chunk may be shorter.
 
         """
         start_, end_ = self._validate_slice(start, end)
@@ -711,6 +1506,11 @@ class Bits:
             raise ValueError("Cannot cut - count must be >= 0.")
         if bits <= 0:
             raise ValueError("Cannot cut - bits must be >= 0.")
+        if exact and (end_ - start_) % bits != 0:
+            raise ValueError(f"Cannot cut {end_ - start_} bits exactly into chunks of {bits} bits.")
+        if bits % 8 == 0 and start_ % 8 == 0:
+            yield from self._cut_bytes(bits // 8, start_, end_, count)
+            return
         c = 0
         while count is None or c < count:
             c += 1
@@ -723,13 +1523,337 @@ class Bits:
             start_ += bits
         return
 
-    def to_bytes(self) -> bytes:
-        """Return the Bits as bytes, padding with zero bits if needed.
+    def _cut_bytes(self, chunk_bytes: int, start: int, end: int, count: int | None) -> Iterator[Bits]:
+        """Fast path for cut when both the chunk size and start position are byte aligned."""
+        whole_chunks = (end - start) // (chunk_bytes * 8)
+        if count is not None:
+            whole_chunks = min(whole_chunks, count)
+        data = memoryview(self._bitstore.getslice(start, start + whole_chunks * chunk_bytes * 8).to_bytes())
+        for i in range(0, whole_chunks * chunk_bytes, chunk_bytes):
+            chunk = self.__class__()
+            chunk._bitstore = BitStore.from_bytes(data[i: i + chunk_bytes])
+            yield chunk
+        start += whole_chunks * chunk_bytes * 8
+        if start < end and (count is None or whole_chunks < count):
+            yield self._slice(start, end)
 
-        Up to seven zero bits will be added at the end to byte align.
+    def deinterleave(self: TBits) -> tuple[TBits, TBits]:
+        """Return a tuple of new Bits from the bits at even positions and the bits at odd positions.
+
+        This is the inverse of interleave. If the length is odd then the first Bits is one bit longer.
+
+        >>> Bits('0xa').deinterleave()
+        (Bits('0b11'), Bits('0b00'))
 
         """
-        return self._bitstore.to_bytes()
+        return self[::2], self[1::2]
+
+    def windows(self, size: int, /) -> Iterator[Bits]:
+        """Return Bits generator of every overlapping window of size bits.
+
+        The windows start at each bit position in turn, and there are len(self) - size + 1
+        of them. Nothing is generated if size > len(self).
+
+        size -- The size in bits of each window.
+
+        Raises ValueError if size <= 0.
+
+        >>> list(Bits('0b1011').windows(3))
+        [Bits('0b101'), Bits('0b011')]
+
+        """
+        if size <= 0:
+            raise ValueError(f"Window size must be > 0, but received {size}.")
+        for pos in range(len(self) - size + 1):
+            yield self._slice(pos, pos + size)
+
+    def run_length_encode(self) -> list[tuple[bool, int]]:
+        """Return a list of (bit value, run length) tuples describing each run of equal bits.
+
+        See also rle_encode_bits, which gives the encoding as a Bits.
+
+        >>> Bits('0b000111100').run_length_encode()
+        [(False, 3), (True, 4), (False, 2)]
+
+        """
+        zero, one = BitStore.from_binstr('0'), BitStore.from_binstr('1')
+        runs = []
+        pos = 0
+        while pos < len(self):
+            value = bool(self._bitstore.getindex(pos))
+            next_pos = self._bitstore.find(zero if value else one, pos, len(self))
+            if next_pos == -1:
+                next_pos = len(self)
+            runs.append((value, next_pos - pos))
+            pos = next_pos
+        return runs
+
+    @classmethod
+    def from_run_length(cls, runs: Iterable[tuple[Any, int]], /) -> TBits:
+        """Create a new Bits from (bit value, run length) tuples. The inverse of run_length_encode.
+
+        Raises ValueError if a run length is negative.
+
+        >>> Bits.from_run_length([(0, 3), (1, 4)])
+        Bits('0b0001111')
+
+        """
+        x = super().__new__(cls)
+        x._bitstore = BitStore()
+        for value, length in runs:
+            if length < 0:
+                raise ValueError(f"Run lengths must be >= 0, but received {length}.")
+            x._bitstore += (BitStore.from_binstr('1') if value else BitStore.from_binstr('0')) * length
+        return x
+
+    def rle_encode_bits(self: TBits, max_run_bits: int, /) -> TBits:
+        """Return a run-length encoding of the Bits as a new Bits.
+
+        Each run of identical bits is encoded as an unsigned count of max_run_bits bits followed
+        by a single bit giving the value of the run. Runs longer than 2**max_run_bits - 1 are split.
+
+        max_run_bits -- The number of bits used to store each run length.
+
+        Raises ValueError if max_run_bits <= 0.
+
+        >>> Bits('0b0000111').rle_encode_bits(3)
+        Bits('0x87')
+
+        """
+        if max_run_bits <= 0:
+            raise ValueError(f"max_run_bits must be > 0, but received {max_run_bits}.")
+        max_run = (1 << max_run_bits) - 1
+        binstr = self._bitstore.slice_to_bin() if len(self) else ''
+        runs = []
+        pos = 0
+        while pos < len(binstr):
+            value = binstr[pos]
+            end = binstr.find('1' if value == '0' else '0', pos)
+            end = len(binstr) if end == -1 else end
+            run = end - pos
+            while run > 0:
+                this_run = min(run, max_run)
+                runs.append(f'{this_run:0{max_run_bits}b}{value}')
+                run -= this_run
+            pos = end
+        bs = object.__new__(self.__class__)
+        bs._bitstore = BitStore.from_binstr(''.join(runs))
+        return bs
+
+    def rle_decode_bits(self: TBits, max_run_bits: int, /) -> TBits:
+        """Return a new Bits by decoding a run-length encoding made with rle_encode_bits.
+
+        max_run_bits -- The number of bits used to store each run length.
+
+        Raises ValueError if max_run_bits <= 0 or if the length of the Bits is not a multiple of max_run_bits + 1.
+
+        >>> Bits('0x87').rle_decode_bits(3)
+        Bits('0b0000111')
+
+        """
+        if max_run_bits <= 0:
+            raise ValueError(f"max_run_bits must be > 0, but received {max_run_bits}.")
+        if len(self) % (max_run_bits + 1) != 0:
+            raise ValueError(f"The Bits length of {len(self)} is not a multiple of max_run_bits + 1 = {max_run_bits + 1}.")
+        binstr = self._bitstore.slice_to_bin() if len(self) else ''
+        runs = []
+        for pos in range(0, len(binstr), max_run_bits + 1):
+            run = int(binstr[pos: pos + max_run_bits], 2)
+            runs.append(binstr[pos + max_run_bits] * run)
+        bs = object.__new__(self.__class__)
+        bs._bitstore = BitStore.from_binstr(''.join(runs))
+        return bs
+
+    def to_bytes(self, pad: str = 'right', fill: Any = False) -> bytes:
+        """Return the Bits as bytes, padding if needed.
+
+        pad -- Where to add up to seven bits to byte align, either 'right' for the end
+               or 'left' for the start. Defaults to 'right'.
+        fill -- If True the padding bits are 1, otherwise they are 0. Defaults to False.
+
+        Padding on the left keeps the value of the Bits as an unsigned integer.
+
+        Raises ValueError if pad is not 'left' or 'right'.
+
+        >>> Bits('0b1, 0xff').to_bytes(pad='left')
+        b'\\x01\\xff'
+
+        """
+        if pad not in ('left', 'right'):
+            raise ValueError(f"pad must be either 'left' or 'right', but received '{pad}'.")
+        padding = -len(self) % 8
+        if padding == 0 or (pad == 'right' and not fill):
+            return self._bitstore.to_bytes()
+        padding = Bits.ones(padding) if fill else Bits.zeros(padding)
+        padded = padding + self if pad == 'left' else self + padding
+        return padded._bitstore.to_bytes()
+
+    def to_base64(self) -> str:
+        """Return the Bits as a base64 encoded string.
+
+        Up to seven zero bits will be added at the end to byte align, as with to_bytes.
+
+        >>> Bits('0x616263').to_base64()
+        'YWJj'
+
+        """
+        return base64.b64encode(self.to_bytes()).decode('ascii')
+
+    def to_base32(self) -> str:
+        """Return the Bits as a base32 encoded string.
+
+        Up to seven zero bits will be added at the end to byte align, as with to_bytes.
+
+        """
+        return base64.b32encode(self.to_bytes()).decode('ascii')
+
+    def to_bools(self) -> list[bool]:
+        """Return the Bits as a list of bools. The inverse of from_bools.
+
+        This is much faster than list(self) for large Bits.
+
+        >>> Bits('0b101').to_bools()
+        [True, False, True]
+
+        """
+        return self._bitstore.tolist()
+
+    def tolist(self) -> list[bool]:
+        """Return the Bits as a list of bools. An alias for to_bools."""
+        return self._bitstore.tolist()
+
+    def to_bool_array(self) -> Any:
+        """Return a numpy array of bools, with one element for each bit.
+
+        Raises ImportError if numpy is not available.
+
+        """
+        try:
+            import numpy as np
+        except ImportError:
+            raise ImportError("The numpy module is needed for to_bool_array, but it could not be imported.")
+        bytes_array = np.frombuffer(self._bitstore.to_bytes(), dtype=np.uint8)
+        return np.unpackbits(bytes_array)[:len(self)].astype(np.bool_)
+
+    def struct_unpack(self, fmt: str, /) -> tuple[Any, ...]:
+        """Unpack the Bits using a format string from the struct module.
+
+        fmt -- A struct module format string, such as '>HHI'.
+
+        Raises ValueError if the Bits is not a whole number of bytes long, and
+        struct.error if the format doesn't match the number of bytes.
+
+        >>> Bits('0x0001ffff').struct_unpack('>Hh')
+        (1, -1)
+
+        """
+        if len(self) % 8 != 0:
+            raise ValueError(f"Bit length must be a multiple of 8 to use struct_unpack, but it is {len(self)}.")
+        return struct.unpack(fmt, self._bitstore.to_bytes())
+
+    def to_uint(self, byteorder: str = 'big') -> int:
+        """Return the Bits interpreted as an unsigned integer.
+
+        byteorder -- Either 'big' or 'little'. Defaults to 'big'. A little-endian
+                     interpretation needs a whole number of bytes.
+
+        Raises ValueError if the Bits is empty, if byteorder is unknown or if byteorder
+        is 'little' and the length isn't a multiple of 8.
+
+        >>> Bits('0xff').to_uint()
+        255
+        >>> Bits('0x0102').to_uint('little')
+        513
+
+        """
+        if self._is_little_endian(byteorder):
+            return self.byteswap()._getuint()
+        return self._getuint()
+
+    def to_int(self, byteorder: str = 'big') -> int:
+        """Return the Bits interpreted as a two's complement signed integer.
+
+        byteorder -- Either 'big' or 'little'. Defaults to 'big'. A little-endian
+                     interpretation needs a whole number of bytes.
+
+        Raises ValueError if the Bits is empty, if byteorder is unknown or if byteorder
+        is 'little' and the length isn't a multiple of 8.
+
+        >>> Bits('0xff').to_int()
+        -1
+
+        """
+        if self._is_little_endian(byteorder):
+            return self.byteswap()._getint()
+        return self._getint()
+
+    def reinterpret_int_width(self: TBits, new_length: int, /, signed: bool = False) -> TBits:
+        """Return new Bits with the same integer value but a different length.
+
+        The Bits is interpreted as an integer, which is then stored using new_length bits.
+        Widening will zero extend for unsigned values, or sign extend for signed values.
+
+        new_length -- The length in bits of the new Bits.
+        signed -- If True the Bits is treated as a two's complement signed integer. Defaults to False.
+
+        Raises ValueError if the Bits is empty or if new_length <= 0.
+        Raises OverflowError if the value doesn't fit in new_length bits.
+
+        >>> Bits('i4=-2').reinterpret_int_width(8, signed=True)
+        Bits('0xfe')
+
+        """
+        if new_length <= 0:
+            raise ValueError(f"The new length must be > 0, but received {new_length}.")
+        value = self._getint() if signed else self._getuint()
+        low, high = (-(1 << (new_length - 1)), (1 << (new_length - 1)) - 1) if signed else (0, (1 << new_length) - 1)
+        if not low <= value <= high:
+            raise OverflowError(f"The value {value} can't be stored in a {'signed' if signed else 'unsigned'} "
+                                f"integer of {new_length} bits.")
+        s = object.__new__(self.__class__)
+        s._bitstore = BitStore.from_int(value, new_length, signed)
+        return s
+
+    def _is_little_endian(self, byteorder: str) -> bool:
+        """Validate byteorder and return True if it's 'little'."""
+        if byteorder == 'big':
+            return False
+        if byteorder != 'little':
+            raise ValueError(f"byteorder must be either 'big' or 'little', but received '{byteorder}'.")
+        if len(self) % 8 != 0:
+            raise ValueError(f"A little-endian interpretation needs a whole number of bytes, "
+                             f"but the Bits has a length of {len(self)}.")
+        return True
+
+    def to_float(self) -> float:
+        """Return the Bits interpreted as a big-endian IEEE 754 floating point number.
+
+        The length must be 16, 32 or 64 bits for half, single or double precision.
+
+        Raises ValueError for any other length.
+
+        >>> Bits('0x3c00').to_float()
+        1.0
+
+        """
+        if len(self) not in (16, 32, 64):
+            raise ValueError(f"A length of 16, 32 or 64 bits is needed to interpret as a float, "
+                             f"but the Bits has a length of {len(self)}.")
+        return self._getfloat()
+
+    def to_hex(self, upper: bool = False) -> str:
+        """Return the Bits as a hexadecimal string, without a '0x' prefix.
+
+        upper -- If True then uppercase letters are used. Defaults to False.
+
+        Raises InterpretError if the length is not a multiple of 4.
+
+        >>> Bits('0xabc').to_hex(upper=True)
+        'ABC'
+
+        """
+        s = self.parse('hex')
+        return s.upper() if upper else s
 
     def starts_with(self, prefix: BitsType, start: int | None = None, end: int | None = None) -> bool:
         """Return whether the current Bits starts with prefix.
@@ -789,19 +1913,140 @@ class Bits:
                 return True
         return False
 
-    def count(self, value: Any) -> int:
+    def and_reduce(self) -> bool:
+        """Return the bit-wise 'and' of every bit. Equivalent to all(1)."""
+        return self._bitstore.all_set()
+
+    def or_reduce(self) -> bool:
+        """Return the bit-wise 'or' of every bit. Equivalent to any(1)."""
+        return self._bitstore.any_set()
+
+    def xor_reduce(self) -> bool:
+        """Return the bit-wise 'xor' of every bit, i.e. True if an odd number of bits are set."""
+        return bool(self._bitstore.count(1) & 1)
+
+    def count(self, value: Any, start: int | None = None, end: int | None = None) -> int:
         """Return count of total number of either zero or one bits.
 
         value -- If bool(value) is True then bits set to 1 are counted, otherwise bits set
                  to 0 are counted.
+        start -- The bit position to start counting from. Defaults to 0.
+        end -- One past the position of the last bit to count. Defaults to len(self).
+
+        Raises ValueError if start or end are out of range, or if end < start.
 
         >>> Bits('0xef').count(1)
         7
 
         """
+        start, end = self._validate_slice(start, end)
         # count the number of 1s (from which it's easy to work out the 0s).
-        count = self._bitstore.count(1)
-        return count if value else len(self) - count
+        count = self._bitstore.count(1, start, end)
+        return count if value else end - start - count
+
+    def hamming_distance(self, other: BitsType, /) -> int:
+        """Return the number of bit positions at which self and other differ.
+
+        Raises ValueError if the two Bits have different lengths.
+
+        >>> Bits('0b1100').hamming_distance('0b1010')
+        2
+
+        """
+        other = Bits._create_from_bitstype(other)
+        self._validate_logical_op_lengths(other)
+        return (self._bitstore ^ other._bitstore).count(1)
+
+    def leading_zeros(self) -> int:
+        """Return the number of zero bits before the first 1 bit.
+
+        Equals len(self) if there are no 1 bits.
+
+        >>> Bits('0b00010100').leading_zeros()
+        3
+
+        """
+        start, end = self._strip_bounds(False)
+        return start if end != 0 else len(self)
+
+    def trailing_zeros(self) -> int:
+        """Return the number of zero bits after the final 1 bit.
+
+        Equals len(self) if there are no 1 bits.
+
+        >>> Bits('0b00010100').trailing_zeros()
+        2
+
+        """
+        _, end = self._strip_bounds(False)
+        return len(self) - end
+
+    def significant_bits(self) -> int:
+        """Return the number of bits needed without any leading zero bits.
+
+        For a non-zero Bits this is the same as the bit_length of its unsigned integer value.
+
+        >>> Bits('0b00010100').significant_bits()
+        5
+
+        """
+        return len(self) - self.leading_zeros()
+
+    def strided_bytes(self: TBits, n: int, /, offset: int = 0) -> TBits:
+        """Return new Bits made from every n-th byte, starting at byte offset.
+
+        Useful for separating channels from byte-interleaved data.
+
+        n -- The step between bytes. Must be > 0.
+        offset -- The byte position of the first byte to use. Defaults to 0.
+
+        Raises ValueError if the Bits is not a whole number of bytes long, if n <= 0 or if offset < 0.
+
+        >>> Bits('0x112233445566').strided_bytes(2, offset=1)
+        Bits('0x224466')
+
+        """
+        if len(self) % 8 != 0:
+            raise ValueError(f"Bit length must be a multiple of 8 to use strided_bytes, but it is {len(self)}.")
+        if n <= 0:
+            raise ValueError(f"n must be > 0, but received {n}.")
+        if offset < 0:
+            raise ValueError(f"offset must be >= 0, but received {offset}.")
+        return self.__class__.from_bytes(self._bitstore.to_bytes()[offset::n])
+
+    def set_bits(self) -> list[int]:
+        """Return a list of the bit positions that are set to 1.
+
+        >>> Bits('0b00101001').set_bits()
+        [2, 4, 7]
+
+        """
+        positions = []
+        for byte_pos, byte in enumerate(self._bitstore.to_bytes()):
+            # Whole zero bytes can be skipped. Any padding bits at the end are always zero.
+            if byte == 0:
+                continue
+            base = byte_pos * 8
+            positions.extend(base + i for i in range(8) if byte & (0x80 >> i))
+        return positions
+
+    def count_byte(self, byte_value: int, /) -> int:
+        """Return the number of whole bytes equal to byte_value.
+
+        byte_value -- The value of the byte to count, in the range [0, 255].
+
+        Raises ValueError if the Bits is not a whole number of bytes long, or if
+        byte_value is out of range.
+
+        >>> Bits('0xff00ffff').count_byte(0xff)
+        3
+
+        """
+        if len(self) % 8 != 0:
+            raise ValueError(f"Bit length must be a multiple of 8 to use count_byte, but it is {len(self)}.")
+        if not 0 <= byte_value <= 255:
+            raise ValueError(f"byte_value must be in the range [0, 255], but received {byte_value}.")
+        return self._bitstore.to_bytes().count(byte_value)
 
     @staticmethod
     def _format_bits(bits: Bits, bits_per_group: int, sep: str, dtype: Dtype,
@@ -973,36 +2218,272 @@ class Bits:
         stream.write(output_stream.getvalue())
         return
 
-    def append(self, bs: BitsType, /) -> TBits:
-        return self + self._create_from_bitstype(bs)
+    @classmethod
+    def _create_from_bitstype_or_int(cls: Type[TBits], bs: BitsType | int, length: int | None) -> TBits:
+        """Create a new Bits from a BitsType, or from an integer with an explicit length."""
+        if isinstance(bs, numbers.Integral):
+            if length is None:
+                raise ValueError(f"A length must be given to convert the integer {bs} to Bits.")
+            return Dtype('u' if bs >= 0 else 'i', length).build(bs)
+        return cls._create_from_bitstype(bs)
+
+    def dump(self, bytes_per_line: int = 16) -> str:
+        """Return a hexdump style string of the Bits.
+
+        Each line has the byte offset, the bytes in hex and then the printable ASCII
+        characters, with '.' used for anything unprintable.
+
+        bytes_per_line -- The number of bytes shown on each line. Defaults to 16.
+
+        Raises ValueError if the Bits is not a whole number of bytes long, or if bytes_per_line <= 0.
+
+        >>> print(Bits.from_bytes(b'hello!').dump())
+        00000000  68 65 6c 6c 6f 21                                |hello!|
+
+        """
+        if len(self) % 8 != 0:
+            raise ValueError(f"Bit length must be a multiple of 8 to use dump, but it is {len(self)}.")
+        if bytes_per_line <= 0:
+            raise ValueError(f"bytes_per_line must be > 0, but received {bytes_per_line}.")
+        data = self._bitstore.to_bytes()
+        lines = []
+        for offset in range(0, len(data), bytes_per_line):
+            chunk = data[offset: offset + bytes_per_line]
+            hex_part = ' '.join(f'{b:02x}' for b in chunk)
+            ascii_part = ''.join(chr(b) if 0x20 <= b < 0x7f else '.' for b in chunk)
+            lines.append(f"{offset:08x}  {hex_part:<{3 * bytes_per_line - 1}}  |{ascii_part}|")
+        return '\n'.join(lines)
+
+    def append(self, bs: BitsType | int, /, length: int | None = None) -> TBits:
+        """Return new Bits with bs appended to the end.
+
+        bs -- The Bits to append. An integer can be used if a length is also given.
+        length -- The number of bits to use when bs is an integer. Non-negative integers are
+                  stored as unsigned, negative integers as two's complement. Ignored for other types.
+
+        """
+        return self + self._create_from_bitstype_or_int(bs, length)
+
+    def prepend(self, bs: BitsType | int, /, length: int | None = None) -> TBits:
+        """Return new Bits with bs prepended to the start.
+
+        bs -- The Bits to prepend. An integer can be used if a length is also given.
+        length -- The number of bits to use when bs is an integer. Non-negative integers are
+                  stored as unsigned, negative integers as two's complement. Ignored for other types.
 
-    def prepend(self, bs: BitsType, /) -> TBits:
-        return self._create_from_bitstype(bs) + self
+        """
+        return self._create_from_bitstype_or_int(bs, length) + self
 
-    def insert(self, bs: BitsType, pos: int, /) -> TBits:
+    def insert(self, bs: BitsType | int, pos: int, /, length: int | None = None) -> TBits:
         """Insert bs at bit position pos.
 
-        bs -- The Bits to insert.
+        bs -- The Bits to insert. An integer can be used if a length is also given.
         pos -- The bit position to insert at.
+        length -- The number of bits to use when bs is an integer. Non-negative integers are
+                  stored as unsigned, negative integers as two's complement. Ignored for other types.
 
         Raises ValueError if pos < 0 or pos > len(self).
 
         """
-        bs = self._create_from_bitstype(bs)
+        bs = self._create_from_bitstype_or_int(bs, length)
         if pos < 0:
             pos += len(self)
         if pos < 0 or pos > len(self):
             raise ValueError("Overwrite starts outside boundary of Bits.")
         return self[:pos] + bs + self[pos:]
 
+    def resize(self: TBits, new_length: int, /, value: Any = False) -> TBits:
+        """Return new Bits of exactly new_length bits, truncating or extending at the end.
+
+        new_length -- The length of the new Bits.
+        value -- If extending, new bits are 1 if value is True, otherwise 0. Defaults to False.
+
+        Raises ValueError if new_length < 0.
+
+        >>> Bits('0xf').resize(6)
+        Bits('0b111100')
+
+        """
+        if new_length < 0:
+            raise ValueError(f"Cannot resize to a negative length of {new_length}.")
+        if new_length <= len(self):
+            return self._slice(0, new_length)
+        extra = Bits.ones(new_length - len(self)) if value else Bits.zeros(new_length - len(self))
+        return self + extra
+
+    def pad_left(self: TBits, total_length: int, /, value: Any = False) -> TBits:
+        """Return new Bits padded at the start to at least total_length bits.
+
+        total_length -- The length to pad to. If already at least this long the Bits is unchanged.
+        value -- If True, pad with 1 bits, otherwise with 0 bits. Defaults to False.
+
+        >>> Bits('0b101').pad_left(8)
+        Bits('0x05')
+
+        """
+        if total_length <= len(self):
+            return self._copy()
+        padding = Bits.ones(total_length - len(self)) if value else Bits.zeros(total_length - len(self))
+        return self.__radd__(padding)
+
+    def pad_right(self: TBits, total_length: int, /, value: Any = False) -> TBits:
+        """Return new Bits padded at the end to at least total_length bits.
+
+        total_length -- The length to pad to. If already at least this long the Bits is unchanged.
+        value -- If True, pad with 1 bits, otherwise with 0 bits. Defaults to False.
+
+        >>> Bits('0b101').pad_right(8)
+        Bits('0xa0')
+
+        """
+        if total_length <= len(self):
+            return self._copy()
+        return self.resize(total_length, value=value)
+
+    def truncate(self: TBits, length: int, /) -> TBits:
+        """Return new Bits with only the first length bits.
+
+        Raises ValueError if length < 0 or length > len(self).
+
+        """
+        if not 0 <= length <= len(self):
+            raise ValueError(f"Cannot truncate Bits of length {len(self)} to a length of {length}.")
+        return self._slice(0, length)
+
+    def remove_range(self: TBits, start: int, end: int | None = None, /) -> tuple[TBits, TBits]:
+        """Remove a range of bits.
+
+        Returns a tuple of new Bits without the range, and new Bits of the bits removed.
+
+        start -- The bit position of the start of the range to remove.
+        end -- The bit position one past the end of the range. Defaults to len(self).
+
+        Negative positions are counted from the end.
+
+        Raises ValueError if the range is outside the Bits or if end < start.
+
+        >>> Bits('0b110011').remove_range(2, 4)
+        (Bits('0xf'), Bits('0b00'))
+
+        """
+        start, end = self._validate_slice(start, end)
+        return self._slice(0, start) + self._slice(end, len(self)), self._slice(start, end)
+
+    def insert_joined(self, sequence: Iterable[BitsType], pos: int, /) -> TBits:
+        """Insert the concatenation of a sequence at bit position pos.
+
+        The items are joined first, so only a single insertion is made.
+
+        sequence -- An iterable of Bits to insert.
+        pos -- The bit position to insert at.
+
+        Raises ValueError if pos < -len(self) or pos > len(self).
+
+        """
+        return self.insert(Bits.join(sequence), pos)
+
     def overwrite(self, bs: BitsType, pos: int, /) -> TBits:
+        """Overwrite with bs at bit position pos.
+
+        bs -- The Bits to overwrite with.
+        pos -- The bit position to start overwriting from. Negative positions are
+               counted from the end, so overwrite(bs, -len(bs)) replaces the final bits.
+
+        The Bits is extended if bs goes past the end, and if pos > len(self) the gap
+        is filled with zeros.
+
+        Raises ValueError if pos < -len(self).
+
+        >>> Bits('0b11').overwrite('0b1', 4)
+        Bits('0b11001')
+
+        """
         bs = self._create_from_bitstype(bs)
         if pos < 0:
             pos += len(self)
-        if pos < 0 or pos > len(self):
-            raise ValueError("Overwrite starts outside boundary of Bits.")
+        if pos < 0:
+            raise ValueError(f"Overwrite starts before the start of Bits of length {len(self)}: pos={pos - len(self)}.")
+        if pos > len(self):
+            return self + Bits.zeros(pos - len(self)) + bs
         return self[:pos] + bs + self[pos + len(bs):]
 
+    def apply_edits(self, edits: Iterable[tuple], /) -> TBits:
+        """Return new Bits with a batch of edits applied.
+
+        edits -- An iterable of operations, each of which is one of
+                 ('insert', pos, bs), ('delete', start, end) or ('overwrite', pos, bs).
+
+        All positions refer to the original Bits, and every edit is validated before any are
+        applied. The affected regions must not overlap, although inserts may be made at the
+        boundary of another edit, in which case they are applied in the order given.
+
+        Raises ValueError if an edit is unknown, out of range or overlaps another edit.
+
+        >>> Bits('0b0000').apply_edits([('insert', 0, '0b1'), ('overwrite', 2, '0b11')])
+        Bits('0b10011')
+
+        """
+        regions = []
+        for edit in edits:
+            op, *args = edit
+            if op == 'insert':
+                pos, bs = args
+                pos = pos + len(self) if pos < 0 else pos
+                if not 0 <= pos <= len(self):
+                    raise ValueError(f"Insert position {args[0]} is outside boundary of Bits of length {len(self)}.")
+                regions.append((pos, pos, self._create_from_bitstype(bs)))
+            elif op == 'delete':
+                start, end = self._validate_slice(*args)
+                regions.append((start, end, None))
+            elif op == 'overwrite':
+                pos, bs = args
+                bs = self._create_from_bitstype(bs)
+                pos = pos + len(self) if pos < 0 else pos
+                if not 0 <= pos <= len(self) - len(bs):
+                    raise ValueError(f"Overwriting {len(bs)} bits at position {args[0]} would go outside "
+                                     f"the boundary of Bits of length {len(self)}.")
+                regions.append((pos, pos + len(bs), bs))
+            else:
+                raise ValueError(f"Unknown edit operation '{op}'. Must be 'insert', 'delete' or 'overwrite'.")
+        regions.sort(key=lambda r: (r[0], r[1]))
+        pieces = []
+        pos = 0
+        for start, end, bs in regions:
+            if start < pos:
+                raise ValueError(f"Edit at position {start} overlaps a previous edit ending at position {pos}.")
+            pieces.append(self._slice(pos, start))
+            if bs is not None:
+                pieces.append(bs)
+            pos = end
+        pieces.append(self._slice(pos, len(self)))
+        return self.__class__.join(pieces)
+
+    def overwrite_from_file(self, path: str | os.PathLike, pos: int, /, length_bytes: int | None = None) -> TBits:
+        """Overwrite with bytes read from a file, starting at bit position pos.
+
+        path -- The file to read from.
+        pos -- The bit position to start overwriting from. Must be a multiple of 8.
+        length_bytes -- The number of bytes to read from the start of the file. Defaults to the whole file.
+
+        Raises ValueError if pos isn't byte aligned, or if the data would go past the end of the Bits.
+        Raises OSError if the file can't be read, or has fewer than length_bytes bytes.
+
+        """
+        if pos < 0:
+            pos += len(self)
+        if pos % 8 != 0:
+            raise ValueError(f"The position must be byte aligned to overwrite from a file, but received {pos}.")
+        if length_bytes is not None and length_bytes < 0:
+            raise ValueError(f"length_bytes must be >= 0, but received {length_bytes}.")
+        with open(path, 'rb') as f:
+            data = f.read() if length_bytes is None else f.read(length_bytes)
+        if length_bytes is not None and len(data) != length_bytes:
+            raise OSError(f"Could only read {len(data)} of the {length_bytes} bytes requested from '{path}'.")
+        if not 0 <= pos <= len(self) - len(data) * 8:
+            raise ValueError(f"Overwriting {len(data)} bytes at bit position {pos} would go outside "
+                             f"the boundary of Bits of length {len(self)}.")
+        return self.overwrite(Bits.from_bytes(data), pos)
+
     def reverse(self, start: int | None = None, end: int | None = None) -> TBits:
         """Reverse bits.
 
@@ -1020,6 +2501,66 @@ class Bits:
         s._bitstore.reverse()
         return self[:start] + s + self[end:]
 
+    def lstrip(self: TBits, value: Any = False, /) -> TBits:
+        """Return new Bits with leading bits equal to value removed.
+
+        value -- If True then leading 1 bits are removed, otherwise leading 0 bits. Defaults to False.
+
+        >>> Bits('0b000101000').lstrip()
+        Bits('0b101000')
+
+        """
+        start, end = self._strip_bounds(value)
+        return self._slice(start, len(self)) if end != 0 else self.__class__()
+
+    def rstrip(self: TBits, value: Any = False, /) -> TBits:
+        """Return new Bits with trailing bits equal to value removed.
+
+        value -- If True then trailing 1 bits are removed, otherwise trailing 0 bits. Defaults to False.
+
+        >>> Bits('0b000101000').rstrip()
+        Bits('0b000101')
+
+        """
+        _, end = self._strip_bounds(value)
+        return self._slice(0, end)
+
+    def strip(self: TBits, value: Any = False, /) -> TBits:
+        """Return new Bits with both leading and trailing bits equal to value removed.
+
+        value -- If True then 1 bits are removed, otherwise 0 bits. Defaults to False.
+
+        >>> Bits('0b000101000').strip()
+        Bits('0b101')
+
+        """
+        return self._slice(*self._strip_bounds(value))
+
+    def _strip_bounds(self, value: Any) -> tuple[int, int]:
+        """Return the start and end of the Bits with any leading and trailing value bits removed."""
+        keep = BitStore.from_binstr('0' if value else '1')
+        start = self._bitstore.find(keep, 0, len(self))
+        if start == -1:
+            return 0, 0
+        return start, self._bitstore.rfind(keep, start, len(self)) + 1
+
+    def reverse_bytes(self: TBits) -> TBits:
+        """Return new Bits with the order of the bytes reversed.
+
+        The order of the bits within each byte is unchanged. See also reverse(), which reverses every bit.
+
+        Raises ValueError if the length is not a multiple of 8.
+
+        >>> Bits('0x112233').reverse_bytes()
+        Bits('0x332211')
+
+        """
+        if len(self) % 8 != 0:
+            raise ValueError(f"Cannot reverse bytes of Bits with a length of {len(self)} as it is not a multiple of 8.")
+        s = object.__new__(self.__class__)
+        s._bitstore = BitStore.from_bytes(self._bitstore.to_bytes()[::-1])
+        return s
+
     def set(self, value: Any, pos: int | Iterable[int] | None = None) -> TBits:
         """Set one or many bits to 1 or 0.
 
@@ -1047,6 +2588,64 @@ class Bits:
             s._bitstore.setitem(p, v)
         return s
 
+    def set_mask(self, value: Any, mask: BitsType, /) -> TBits:
+        """Return new Bits with every bit where mask is 1 set to value.
+
+        value -- If True, set the bits to 1, otherwise set them to 0.
+        mask -- A Bits of the same length as self.
+
+        Raises ValueError if the mask has a different length.
+
+        >>> Bits('0b1100').set_mask(1, '0b0101')
+        Bits('0xd')
+
+        """
+        mask = Bits._create_from_bitstype(mask)
+        self._validate_logical_op_lengths(mask)
+        if value:
+            return self | mask
+        return self & ~mask
+
+    def setall(self, value: Any, /, start: int | None = None, end: int | None = None) -> TBits:
+        """Return new Bits with every bit in a range set to value.
+
+        value -- If True, set the bits to 1, otherwise set them to 0.
+        start -- Position of first bit to set. Defaults to 0.
+        end -- One past the position of the last bit to set. Defaults to len(self).
+
+        Raises ValueError if start or end are out of range, or if end < start.
+
+        >>> Bits('0x0f').setall(1, 2, 6)
+        Bits('0x3f')
+
+        """
+        start, end = self._validate_slice(start, end)
+        s = self._copy()
+        s._bitstore.setitem(slice(start, end), 1 if value else 0)
+        return s
+
+    def zero_range(self, start: int | None = None, end: int | None = None) -> TBits:
+        """Return new Bits with all bits in a range set to 0.
+
+        start -- Position of first bit to set. Defaults to 0.
+        end -- One past the position of the last bit to set. Defaults to len(self).
+
+        Raises ValueError if start or end are out of range, or if end < start.
+
+        """
+        return self.setall(0, start, end)
+
+    def one_range(self, start: int | None = None, end: int | None = None) -> TBits:
+        """Return new Bits with all bits in a range set to 1.
+
+        start -- Position of first bit to set. Defaults to 0.
+        end -- One past the position of the last bit to set. Defaults to len(self).
+
+        Raises ValueError if start or end are out of range, or if end < start.
+
+        """
+        return self.setall(1, start, end)
+
     def invert(self, pos: Iterable[int] | int | None = None) -> TBits:
         """Invert one or many bits from 0 to 1 or vice versa.
 
@@ -1065,48 +2664,154 @@ class Bits:
         length = len(self)
 
         for p in pos:
-            if p < 0:
-                p += length
-            if not 0 <= p < length:
-                raise IndexError(f"Bit position {p} out of range.")
-            s._invert(p)
+            q = p + length if p < 0 else p
+            if not 0 <= q < length:
+                raise IndexError(f"Bit position {p} out of range for Bits of length {length}.")
+            s._invert(q)
         return s
 
+    def toggle(self, pos: Iterable[int] | int, /) -> TBits:
+        """Return new Bits with one or many bits flipped from 0 to 1 or vice versa.
+
+        Unlike invert, a position must always be given.
+
+        pos -- Either a single bit position or an iterable of bit positions.
+               Negative numbers are treated in the same way as slice indices.
+
+        Raises IndexError if pos < -len(self) or pos >= len(self).
+
+        """
+        if pos is None:
+            raise ValueError("A bit position or iterable of bit positions must be given to toggle.")
+        return self.invert(pos)
+
+    def _rotate_left(self: TBits, n: int, start: int | None, end: int | None) -> TBits:
+        """Return new Bits with the slice rotated left by n bits. A negative n rotates right."""
+        start, end = self._validate_slice(start, end)
+        if start == end:
+            return self._copy()
+        n %= (end - start)
+        return self[:start] + self[start + n: end] + self[start: start + n] + self[end:]
+
     def ror(self, n: int, /, start: int | None = None, end: int | None = None) -> TBits:
-        """Rotate bits to the right in-place.
+        """Return new Bits with bits rotated to the right.
 
         n -- The number of bits to rotate by.
         start -- Start of slice to rotate. Defaults to 0.
         end -- End of slice to rotate. Defaults to len(self).
 
-        Raises ValueError if bits < 0.
+        Rotating an empty Bits or slice has no effect.
+
+        Raises ValueError if n < 0.
 
         """
-        if len(self) == 0:
-            raise ValueError("Cannot rotate an empty Bits.")
         if n < 0:
             raise ValueError("Cannot rotate by negative amount.")
-        start, end = self._validate_slice(start, end)
-        n %= (end - start)
-        return self[:start] + self[end - n: end] + self[start: end - n] + self[end:]
+        return self._rotate_left(-n, start, end)
 
     def rol(self, n: int, /, start: int | None = None, end: int | None = None) -> TBits:
-        """Rotate bits to the left in-place.
+        """Return new Bits with bits rotated to the left.
 
         n -- The number of bits to rotate by.
         start -- Start of slice to rotate. Defaults to 0.
         end -- End of slice to rotate. Defaults to len(self).
 
-        Raises ValueError if bits < 0.
+        Rotating an empty Bits or slice has no effect.
+
+        Raises ValueError if n < 0.
 
         """
-        if not len(self):
-            raise ValueError("Cannot rotate an empty Bits.")
         if n < 0:
             raise ValueError("Cannot rotate by negative amount.")
-        start, end = self._validate_slice(start, end)
-        n %= (end - start)
-        return self[:start] + self[start + n: end] + self[start: start + n] + self[end:]
+        return self._rotate_left(n, start, end)
+
+    def rcl(self: TBits, n: int, /, carry_in: Any = False) -> tuple[TBits, bool]:
+        """Rotate bits to the left through a carry bit.
+
+        The carry bit and the Bits are treated as a single rotation of len(self) + 1 bits,
+        with the carry bit before the first bit. Returns a tuple of the new Bits and the carry out.
+
+        n -- The number of bits to rotate by.
+        carry_in -- The initial value of the carry bit. Defaults to False.
+
+        Raises ValueError if n < 0.
+
+        >>> Bits('0b1000').rcl(1)
+        (Bits('0b0000'), True)
+
+        """
+        combined = Bits.join(['0b1' if carry_in else '0b0', self]).rol(n)
+        return self.__class__._create_from_bitstype(combined[1:]), combined[0]
+
+    def rcr(self: TBits, n: int, /, carry_in: Any = False) -> tuple[TBits, bool]:
+        """Rotate bits to the right through a carry bit.
+
+        The carry bit and the Bits are treated as a single rotation of len(self) + 1 bits,
+        with the carry bit before the first bit. Returns a tuple of the new Bits and the carry out.
+
+        n -- The number of bits to rotate by.
+        carry_in -- The initial value of the carry bit. Defaults to False.
+
+        Raises ValueError if n < 0.
+
+        >>> Bits('0b0001').rcr(1, carry_in=True)
+        (Bits('0x8'), True)
+
+        """
+        combined = Bits.join(['0b1' if carry_in else '0b0', self]).ror(n)
+        return self.__class__._create_from_bitstype(combined[1:]), combined[0]
+
+    def _gray_words(self: TBits, word_bits: int | None, transform) -> TBits:
+        """Apply an integer transform to each word_bits sized word and return new Bits."""
+        if word_bits is None:
+            word_bits = len(self)
+        elif word_bits <= 0:
+            raise ValueError(f"word_bits must be > 0, but received {word_bits}.")
+        elif len(self) % word_bits != 0:
+            raise ValueError(f"The Bits length of {len(self)} is not a multiple of word_bits = {word_bits}.")
+        if len(self) == 0:
+            return self.__class__()
+        s = object.__new__(self.__class__)
+        s._bitstore = BitStore()
+        for start in range(0, len(self), word_bits):
+            word = self._bitstore.slice_to_uint(start, start + word_bits)
+            s._bitstore += BitStore.from_int(transform(word), word_bits, False)
+        return s
+
+    def to_gray(self: TBits, word_bits: int | None = None) -> TBits:
+        """Return new Bits converted from binary to reflected binary Gray code.
+
+        word_bits -- The size of each word to convert independently. Defaults to treating
+                     the whole Bits as a single big-endian number.
+
+        Raises ValueError if word_bits <= 0 or if the length is not a multiple of word_bits.
+
+        >>> Bits('0b0111').to_gray()
+        Bits('0x4')
+
+        """
+        return self._gray_words(word_bits, lambda b: b ^ (b >> 1))
+
+    def from_gray(self: TBits, word_bits: int | None = None) -> TBits:
+        """Return new Bits converted from reflected binary Gray code to binary.
+
+        word_bits -- The size of each word to convert independently. Defaults to treating
+                     the whole Bits as a single big-endian number.
+
+        Raises ValueError if word_bits <= 0 or if the length is not a multiple of word_bits.
+
+        >>> Bits('0b0100').from_gray()
+        Bits('0x7')
+
+        """
+        def decode(g: int) -> int:
+            b = g
+            g >>= 1
+            while g:
+                b ^= g
+                g >>= 1
+            return b
+        return self._gray_words(word_bits, decode)
 
     def byteswap(self, bytelength: int | None = None, /) -> TBits:
         """Change the byte endianness. Return new Bits.
@@ -1135,9 +2840,10 @@ class Bits:
 
     def replace(self, old: BitsType, new: BitsType, /, start: int | None = None, end: int | None = None,
                 count: int | None = None, bytealigned: bool | None = None) -> TBits:
-        """Replace all occurrences of old with new in place.
+        """Return new Bits with occurrences of old replaced with new.
 
-        Returns number of replacements made.
+        Occurrences are found from the start and can't overlap, so replacing '0b11' in '0b111'
+        only replaces the first two bits. The replacement can be a different length to old.
 
         old -- The Bits to replace.
         new -- The replacement Bits.
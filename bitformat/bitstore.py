@@ -3,6 +3,7 @@ from __future__ import annotations
 import bitarray
 import bitarray.util
 import copy
+import itertools
 import struct
 from typing import Union, Iterable, Iterator, Any
 
@@ -32,7 +33,8 @@ class BitStore:
     @classmethod
     def from_zeros(cls, i: int) -> BitStore:
         x = super().__new__(cls)
-        x._bitarray = bitarray.bitarray(i)
+        # A plain bitarray(i) has uninitialised contents, so explicitly zero it.
+        x._bitarray = bitarray.util.zeros(i)
         return x
 
     @classmethod
@@ -41,6 +43,12 @@ class BitStore:
         x._bitarray = bitarray.bitarray(s)
         return x
 
+    @classmethod
+    def from_bools(cls, values: Iterable[Any], /) -> BitStore:
+        x = super().__new__(cls)
+        x._bitarray = bitarray.bitarray(list(map(bool, values)))
+        return x
+
     @classmethod
     def from_bytes(cls, b: Union[bytes, bytearray, memoryview], /) -> BitStore:
         x = super().__new__(cls)
@@ -136,6 +144,9 @@ class BitStore:
         bs += other
         return bs
 
+    def __mul__(self, n: int, /) -> BitStore:
+        return BitStore.from_bitarray(self._bitarray * n)
+
     def __eq__(self, other: Any, /) -> bool:
         return self._bitarray == other._bitarray
 
@@ -204,6 +215,15 @@ class BitStore:
                 if (p % 8) == 0:
                     yield p
 
+    def findall_list(self, bs: BitStore, start: int, end: int, bytealigned: bool = False) -> list[int]:
+        if bytealigned and len(bs) % 8 == 0:
+            return list(self.findall(bs, start, end, bytealigned))
+        # Collect directly from bitarray's search rather than going through the findall generator.
+        positions = list(self._bitarray.itersearch(bs._bitarray, start, end))
+        if bytealigned:
+            return [p for p in positions if p % 8 == 0]
+        return positions
+
     def rfindall(self, bs: BitStore, start: int, end: int, bytealigned: bool = False) -> Iterator[int]:
         i = self._bitarray.itersearch(bs._bitarray, start, end, right=True)
         if not bytealigned:
@@ -214,8 +234,10 @@ class BitStore:
                 if (p % 8) == 0:
                     yield p
 
-    def count(self, value, /) -> int:
-        return self._bitarray.count(value)
+    def count(self, value, /, start: int = 0, end: int | None = None) -> int:
+        if end is None:
+            end = len(self)
+        return self._bitarray.count(value, start, end)
 
     def clear(self) -> None:
         self._bitarray.clear()
@@ -227,6 +249,9 @@ class BitStore:
         for i in range(len(self)):
             yield self.getindex(i)
 
+    def tolist(self) -> list[bool]:
+        return list(map(bool, self._bitarray.tolist()))
+
     def _copy(self) -> BitStore:
         """Always creates a copy, even if instance is immutable."""
         s_copy = self.__class__()
@@ -243,6 +268,9 @@ class BitStore:
     def getslice_withstep(self, key: slice, /) -> BitStore:
         return BitStore.from_bitarray(self._bitarray.__getitem__(key))
 
+    def getmasked(self, mask: BitStore, /) -> BitStore:
+        return BitStore.from_bitarray(bitarray.bitarray(list(itertools.compress(self._bitarray, mask._bitarray))))
+
     def getslice(self, start: int | None, stop: int | None, /) -> BitStore:
         return BitStore.from_bitarray(self._bitarray[start:stop])
 
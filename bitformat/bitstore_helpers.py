@@ -65,6 +65,21 @@ def str_to_bitstore(s: str) -> BitStore:
     return bs
 
 
+def check_literals_strict(s: str) -> None:
+    """Raise ValueError if any literal in s could be a typo.
+
+    Hex literals must have an even number of digits, and underscores in literals
+    can only be used singly between digits.
+    """
+    for name, _, value in tokenparser(s):
+        if name not in literal_bit_funcs:
+            continue
+        if value.startswith('_') or value.endswith('_') or '__' in value:
+            raise ValueError(f"Underscores in the literal '{name}{value}' must each be between two digits.")
+        if name.lower() == '0x' and len(value.replace('_', '')) % 2 != 0:
+            raise ValueError(f"The hex literal '{name}{value}' has an odd number of digits.")
+
+
 literal_bit_funcs: Dict[str, Callable[..., BitStore]] = {
     '0x': BitStore.from_hex,
     '0X': BitStore.from_hex,
@@ -75,6 +90,44 @@ literal_bit_funcs: Dict[str, Callable[..., BitStore]] = {
 }
 
 
+def register_literal_prefix(prefix: str, bits_per_char: int, char_map: Dict[str, int]) -> None:
+    """Register a new literal prefix for use in formatted strings, such as '0q' for quaternary.
+
+    prefix -- A '0' followed by a single letter. The letter is case insensitive.
+    bits_per_char -- The number of bits that each character represents.
+    char_map -- A dict mapping each allowed character to its integer value.
+
+    >>> register_literal_prefix('0q', 2, {'0': 0, '1': 1, '2': 2, '3': 3})
+    >>> Bits('0q0123')
+    Bits('0x1b')
+
+    """
+    global LITERAL_RE
+    if not re.fullmatch(r'0[a-zA-Z]', prefix):
+        raise ValueError(f"A literal prefix must be a '0' followed by a single letter, but received '{prefix}'.")
+    if prefix.lower() in ('0x', '0o', '0b'):
+        raise ValueError(f"The literal prefix '{prefix}' is built in and can't be replaced.")
+    if bits_per_char <= 0:
+        raise ValueError(f"bits_per_char must be > 0, but received {bits_per_char}.")
+    for char, value in char_map.items():
+        if not 0 <= value < (1 << bits_per_char):
+            raise ValueError(f"The value {value} for character '{char}' doesn't fit in {bits_per_char} bits.")
+    char_bits = {char: f'{value:0{bits_per_char}b}' for char, value in char_map.items()}
+
+    def from_literal(s: str) -> BitStore:
+        s = ''.join(s.split()).replace('_', '')
+        try:
+            return BitStore.from_binstr(''.join(char_bits[c] for c in s))
+        except KeyError as e:
+            raise ValueError(f"Invalid character {e} in '{prefix}' initialiser '{s}'.")
+
+    literal_bit_funcs[prefix.lower()] = literal_bit_funcs[prefix.upper()] = from_literal
+    letters = ''.join(sorted({p[1].lower() for p in literal_bit_funcs}))
+    LITERAL_RE = re.compile(rf'^(?P<name>0([{letters}]))(?P<value>.+)', re.IGNORECASE)
+    tokenparser.cache_clear()
+    str_to_bitstore.cache_clear()
+
+
 def bitstore_from_token(name: str, token_length: int | None, value: str | None) -> BitStore:
     try:
         f = literal_bit_funcs[name]
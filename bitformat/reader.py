@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+from bitformat.bits import Bits, BitsType
+
+
+class Reader:
+    """Read sequentially through a Bits, keeping track of the current bit position.
+
+    The Bits being read isn't copied or modified.
+
+    >>> r = Reader(Bits('0x12f'))
+    >>> r.read(4)
+    Bits('0x1')
+    >>> r.tell()
+    4
+
+    """
+
+    def __init__(self, bits: BitsType, pos: int = 0) -> None:
+        self._bits = Bits._create_from_bitstype(bits)
+        self._pos = 0
+        self.seek(pos)
+
+    @property
+    def bits(self) -> Bits:
+        """The Bits being read. Read only."""
+        return self._bits
+
+    def read(self, length: int, /) -> Bits:
+        """Return the next length bits and advance the position past them.
+
+        Raises ValueError if length < 0 or if there aren't enough bits left.
+
+        """
+        bs = self.peek(length)
+        self._pos += length
+        return bs
+
+    def peek(self, length: int, /) -> Bits:
+        """Return the next length bits without changing the position.
+
+        Raises ValueError if length < 0 or if there aren't enough bits left.
+
+        """
+        if length < 0:
+            raise ValueError(f"Cannot read a negative length of {length} bits.")
+        if self._pos + length > len(self._bits):
+            raise ValueError(f"Needed a length of at least {length} bits, but only "
+                             f"{len(self._bits) - self._pos} bits were available.")
+        return self._bits._slice(self._pos, self._pos + length)
+
+    def seek(self, pos: int, /) -> None:
+        """Set the current bit position. Negative positions are counted from the end.
+
+        Raises ValueError if pos is outside the Bits.
+
+        """
+        if pos < 0:
+            pos += len(self._bits)
+        if not 0 <= pos <= len(self._bits):
+            raise ValueError(f"Cannot seek to position {pos} in Bits of length {len(self._bits)}.")
+        self._pos = pos
+
+    def tell(self) -> int:
+        """Return the current bit position."""
+        return self._pos
+
+    def __repr__(self) -> str:
+        return f"{self.__class__.__name__}({self._bits!r}, pos={self._pos})"
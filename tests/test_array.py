@@ -933,3 +933,13 @@ class TestDelegation:
         assert isinstance(a.data, collections.abc.Hashable) is False
         with pytest.raises(TypeError):
             _ = set([a.data])
+
+    @pytest.mark.skipif(sys.version_info < (3, 12), reason="The buffer protocol needs Python 3.12 or later.")
+    def test_buffer(self):
+        a = Array('u8', [1, 2, 255])
+        assert memoryview(a.data).tobytes() == b'\x01\x02\xff'
+        a.append(3)
+        assert bytearray(a.data) == bytearray(b'\x01\x02\xff\x03')
+        b = Array('u4', [1, 2, 3])
+        with pytest.raises(BufferError):
+            memoryview(b.data)
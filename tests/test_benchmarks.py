@@ -17,6 +17,50 @@ def test_cutting(benchmark):
     c = benchmark(cut)
     assert c == 12000, c
 
+def test_cutting_bytes(benchmark):
+    def cut():
+        s = bitformat.Bits.from_bytes(bytes(range(256)) * 40960)
+        c = 0
+        for chunk in s.cut(64):
+            if chunk == '0x0001020304050607':
+                c += 1
+        return c
+    c = benchmark(cut)
+    assert c == 40960, c
+
+def test_tolist(benchmark):
+    def tolist():
+        s = bitformat.Bits.from_bytes(bytes(range(256)) * 500)[:1000000]
+        return s.tolist()
+    x = benchmark(tolist)
+    assert len(x) == 1000000
+    assert x.count(True) == 499924
+
+def test_repeat(benchmark):
+    def repeat():
+        return bitformat.Bits('0b1') * 1_000_000
+    s = benchmark(repeat)
+    assert len(s) == 1_000_000
+
+def test_find_all_positions(benchmark):
+    def find_all_positions():
+        s = bitformat.Bits('0b0001') * 100000
+        return s.find_all_positions('0b1')
+    x = benchmark(find_all_positions)
+    assert len(x) == 100000
+
+def test_equality(benchmark):
+    def equality():
+        a = bitformat.Bits.zeros(1000)
+        others = [bitformat.Bits.zeros(999), bitformat.Bits.zeros(1001), '0x00', a[:]]
+        c = 0
+        for _ in range(10000):
+            for other in others:
+                c += (a == other)
+        return c
+    c = benchmark(equality)
+    assert c == 10000
+
 # def test_count(benchmark):
 #     def count():
 #         s = bitformat.BitArray(100000000)
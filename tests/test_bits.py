@@ -1,11 +1,14 @@
 #!/usr/bin/env python
 import pytest
 import io
+import sys
+import math
 import re
 from hypothesis import given
 import hypothesis.strategies as st
 import bitformat
 from bitformat import Dtype, Bits
+from bitformat import bitstore_helpers
 
 
 def test_build():
@@ -19,6 +22,22 @@ def test_build():
     assert c.parse('f64') == 13.75
 
 
+@pytest.mark.parametrize("dtype, value", [('u12', 31), ('uint8', 255), ('i16', -300), ('int3', 3), ('u100', 2**99)])
+def test_build_integers_matches_dtype_build(dtype, value):
+    a = Bits.build(dtype, value)
+    assert a == Dtype(dtype).build(value)
+    assert a.parse(dtype) == value
+
+
+def test_build_integer_errors():
+    with pytest.raises(ValueError):
+        Bits.build('u8', 256)
+    with pytest.raises(ValueError):
+        Bits.build('i8', 128)
+    with pytest.raises(ValueError):
+        Bits.build('u8', -1)
+
+
 def remove_unprintable(s: str) -> str:
     colour_escape = re.compile(r'(?:\x1B[@-_])[0-?]*[ -/]*[@-~]')
     return colour_escape.sub('', s)
@@ -132,6 +151,21 @@ class TestInitialisation:
         b = a.rfind('0b010')
         assert b == 11
 
+    def test_rfind_with_start_is_absolute(self):
+        a = Bits('0x0f0f')
+        assert a.rfind('0xf', start=4) == 12
+        assert a.rfind('0xf', start=4, end=12) == 4
+
+    def test_find_with_end(self):
+        a = Bits('0x00f0f0')
+        assert a.find('0xf', end=11) is None
+        assert a.find('0xf', end=12) == 8
+        assert a.find('0xf', start=9, end=16) is None
+        assert a.find('0xf', start=9) == 16
+        assert a.find('0xf', start=9, end=1000) == 16
+        assert a.find('0x0f', end=20, bytealigned=True) is None
+        assert a.find('0xf0', end=24, bytealigned=True) == 8
+
     def test_find_all(self):
         a = Bits('0b0010011')
         b = list(a.find_all([1]))
@@ -147,6 +181,35 @@ class TestCut:
         for t in s.cut(6):
             assert t == '0b000111'
 
+    def test_editing_chunks_does_not_change_source(self):
+        s = Bits('0x0000')
+        edited = [t.set(1, 0) for t in s.cut(4)]
+        assert edited == ['0x8'] * 4
+        assert s == '0x0000'
+
+    @pytest.mark.parametrize('bits', [8, 16, 24, 64])
+    @pytest.mark.parametrize('start, end', [(None, None), (8, None), (0, 90), (16, 93), (8, 8)])
+    @pytest.mark.parametrize('count', [None, 0, 1, 3])
+    def test_byte_aligned_cut_matches_slicing(self, bits, start, end, count):
+        s = Bits.from_bytes(bytes(range(13)))[:-3]
+        start_, end_ = s._validate_slice(start, end)
+        expected = [s[p: min(p + bits, end_)] for p in range(start_, end_, bits)]
+        if count is not None:
+            expected = expected[:count]
+        chunks = list(s.cut(bits, start, end, count))
+        assert chunks == expected
+        assert all(type(c) is Bits for c in chunks)
+
+    def test_exact(self):
+        s = Bits('0x12345')
+        assert list(s.cut(4, exact=True)) == list(s.cut(4))
+        assert list(s.cut(8, end=16, exact=True)) == ['0x12', '0x34']
+        assert list(s.cut(8)) == ['0x12', '0x34', '0x5']
+        with pytest.raises(ValueError):
+            list(s.cut(8, exact=True))
+        with pytest.raises(ValueError):
+            list(s.cut(3, start=4, count=1, exact=True))
+
 def test_unorderable():
     a = Bits('0b000111')
     b = Bits('0b000111')
@@ -200,6 +263,11 @@ class TestContainsBug:
         assert '0b1' in Bits.from_string('0xf')
         assert not '0b0' in Bits.from_string('0xf')
 
+    def test_contains_empty(self):
+        assert '' in Bits('0x1')
+        assert Bits() in Bits()
+        assert '0b1' not in Bits()
+
 
 class TestUnderscoresInLiterals:
     def test_hex_creation(self):
@@ -508,3 +576,1712 @@ class TestPrettyPrinting_NewFormats:
  0:  4635066033680416768 :                    76.25
 ] + trailing_bits = 0b11111
 """
+
+
+class TestRunLengthBits:
+
+    def test_encode(self):
+        a = Bits('0b0000111')
+        assert a.rle_encode_bits(3) == '0b1000_0111'
+        assert Bits().rle_encode_bits(4) == Bits()
+
+    def test_long_runs_are_split(self):
+        a = Bits.zeros(10)
+        b = a.rle_encode_bits(2)
+        assert b == '0b110, 0b110, 0b110, 0b010'
+        assert b.rle_decode_bits(2) == a
+
+    @pytest.mark.parametrize("max_run_bits", [1, 2, 3, 8])
+    def test_round_trip(self, max_run_bits):
+        a = Bits('0b1110000000001011, 0x00ff1')
+        assert a.rle_encode_bits(max_run_bits).rle_decode_bits(max_run_bits) == a
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').rle_encode_bits(0)
+        with pytest.raises(ValueError):
+            Bits('0b10101').rle_decode_bits(3)
+
+
+class TestOverwrite:
+
+    def test_overwrite(self):
+        a = Bits('0x0000')
+        assert a.overwrite('0xf', 4) == '0x0f00'
+        assert a.overwrite('0b1', 0) == '0x8000'
+
+    def test_negative_position(self):
+        a = Bits('0x0000')
+        b = Bits('0xab')
+        assert a.overwrite(b, -len(b)) == '0x00ab'
+        assert a.overwrite('0b11', -3) == '0b0000000000000110'
+        assert a.overwrite('0xf', -16) == '0xf000'
+
+    def test_out_of_range(self):
+        a = Bits('0x0000')
+        with pytest.raises(ValueError):
+            a.overwrite('0b1', -17)
+
+    def test_extends_past_end(self):
+        a = Bits('0b11')
+        assert a.overwrite('0b01', 1) == '0b101'
+        assert a.overwrite('0b1', 2) == '0b111'
+        assert a.overwrite('0b1', 4) == '0b11001'
+        assert Bits().overwrite('0xf', 4) == '0x0f'
+
+
+class TestCountByte:
+
+    def test_count_byte(self):
+        a = Bits('0xff00ffff01')
+        assert a.count_byte(0xff) == 3
+        assert a.count_byte(0) == 1
+        assert a.count_byte(2) == 0
+        assert Bits().count_byte(0) == 0
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0xfff').count_byte(0xff)
+        with pytest.raises(ValueError):
+            Bits('0xff').count_byte(256)
+        with pytest.raises(ValueError):
+            Bits('0xff').count_byte(-1)
+
+
+class TestErrorMessages:
+
+    def test_logical_op_lengths(self):
+        a = Bits('0x0f')
+        with pytest.raises(ValueError, match="8 and 12 bits"):
+            _ = a & '0x123'
+        with pytest.raises(ValueError, match="8 and 4 bits"):
+            _ = a | '0xf'
+        with pytest.raises(ValueError, match="8 and 1 bits"):
+            _ = a ^ '0b1'
+
+    def test_index_out_of_range(self):
+        a = Bits('0b101')
+        with pytest.raises(IndexError, match="position 3 .* length 3") as e:
+            _ = a[3]
+        assert e.value.__suppress_context__
+        with pytest.raises(IndexError, match="position -5 .* length 3"):
+            _ = a.invert(-2).invert(-5)
+
+    def test_slice_positions(self):
+        a = Bits.zeros(10)
+        with pytest.raises(ValueError, match="length 10: start=4, end=11"):
+            a.reverse(4, 11)
+
+    def test_bytes_interpretation(self):
+        with pytest.raises(ValueError, match="length of 12"):
+            _ = Bits('0x123').bytes
+
+
+class TestAddingIntegers:
+
+    def test_append(self):
+        a = Bits('0b1')
+        assert a.append(5, length=4) == '0b1, 0b0101'
+        assert a.append(-1, length=3) == '0b1111'
+        assert a.append('0b00', length=10) == '0b100'
+
+    def test_prepend(self):
+        a = Bits('0xf')
+        assert a.prepend(3, length=4) == '0x3f'
+
+    def test_insert(self):
+        a = Bits('0x00')
+        assert a.insert(15, 4, length=4) == '0x0f0'
+
+    def test_errors(self):
+        a = Bits('0x00')
+        with pytest.raises(ValueError):
+            a.append(3)
+        with pytest.raises(ValueError):
+            a.append(16, length=4)
+        with pytest.raises(ValueError):
+            a.insert(1, 0)
+
+
+class TestCompare:
+
+    def test_compare(self):
+        a = Bits('0b011')
+        assert a.compare('0b1') == -1
+        assert a.compare('0b011') == 0
+        assert a.compare('0b01') == 1
+        assert a.compare('0b0110') == -1
+        assert Bits().compare(Bits()) == 0
+        assert Bits().compare('0b0') == -1
+
+    def test_sorting(self):
+        import functools
+        bits = [Bits('0xf'), Bits('0b0'), Bits('0b01'), Bits('0b001'), Bits()]
+        s = sorted(bits, key=functools.cmp_to_key(Bits.compare))
+        assert s == [Bits(), '0b0', '0b001', '0b01', '0xf']
+
+
+class TestReverseRange:
+
+    def test_reverse_sub_range(self):
+        a = Bits('0b1100_0000_0011')
+        assert a.reverse(0, 4) == '0b0011_0000_0011'
+        assert a.reverse(8) == '0b1100_0000_1100'
+        assert a.reverse(-4) == '0b1100_0000_1100'
+        assert a.reverse(2, 2) == a
+
+    def test_reverse_range_errors(self):
+        a = Bits('0x0')
+        with pytest.raises(ValueError):
+            a.reverse(3, 2)
+        with pytest.raises(ValueError):
+            a.reverse(0, 5)
+
+
+class TestStrided:
+
+    def test_strided(self):
+        a = Bits('0b10101010')
+        assert a.strided(0, None, 2) == '0xf'
+        assert a.strided(1, None, 2) == '0x0'
+        assert a.strided(None, None, -1) == a[::-1]
+        assert a.strided(1, 7, 3) == a[1:7:3]
+        assert a.strided(-3, None, 1) == '0b010'
+
+    def test_zero_step(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').strided(0, 1, 0)
+
+
+class TestSetBits:
+
+    def test_set_bits(self):
+        assert Bits('0b00101001').set_bits() == [2, 4, 7]
+        assert Bits().set_bits() == []
+        assert Bits.zeros(100).set_bits() == []
+        assert Bits.ones(10).set_bits() == list(range(10))
+
+    def test_sparse(self):
+        a = Bits.zeros(1000).set(1, [3, 500, 999])
+        assert a.set_bits() == [3, 500, 999]
+
+
+class TestFromPositions:
+
+    def test_from_positions(self):
+        assert Bits.from_positions([0, 3], 4) == '0x9'
+        assert Bits.from_positions([], 5) == '0b00000'
+        assert Bits.from_positions([], 0) == Bits()
+        assert Bits.from_positions({7, 1, 1}, 8) == '0x41'
+
+    def test_round_trip(self):
+        a = Bits('0x1234567')
+        assert Bits.from_positions(a.set_bits(), len(a)) == a
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits.from_positions([4], 4)
+        with pytest.raises(ValueError):
+            Bits.from_positions([-1], 4)
+
+
+class TestToggle:
+
+    def test_toggle(self):
+        a = Bits('0b0000')
+        assert a.toggle(0) == '0b1000'
+        assert a.toggle(-1) == '0b0001'
+        assert a.toggle([1, 2]) == '0b0110'
+        assert a.toggle(1).toggle(1) == a
+
+    def test_errors(self):
+        a = Bits('0b0000')
+        with pytest.raises(IndexError):
+            a.toggle(4)
+        with pytest.raises(ValueError):
+            a.toggle(None)
+
+
+class TestMajority:
+
+    def test_three_inputs(self):
+        a = Bits('0b1100')
+        assert a.majority(['0b1010', '0b0110']) == '0b1110'
+        assert a.majority([a, '0x0']) == a
+
+    def test_single_input(self):
+        a = Bits('0b1011')
+        assert a.majority([]) == a
+
+    def test_tie_gives_zero(self):
+        assert Bits('0b1100').majority(['0b1010']) == '0b1000'
+
+    def test_many_inputs(self):
+        inputs = [Bits.from_random(100, seed=i) for i in range(7)]
+        expected = Bits.from_bools(sum(bs[i] for bs in inputs) >= 4 for i in range(100))
+        assert inputs[0].majority(inputs[1:]) == expected
+        assert inputs[0].majority(inputs[1:6]) == Bits.from_bools(sum(bs[i] for bs in inputs[:6]) >= 4 for i in range(100))
+        assert Bits('0x0f').majority(['0x00'] * 4) == '0x00'
+
+    def test_length_mismatch(self):
+        with pytest.raises(ValueError):
+            Bits('0b1100').majority(['0b101', '0b1111'])
+
+
+class TestWholeSlice:
+
+    def test_whole_slice_get(self):
+        a = Bits('0b10110')
+        assert a[:] == a
+        assert len(a[:]) == 5
+
+    def test_whole_slice_assignment_not_allowed(self):
+        a = Bits('0b10110')
+        with pytest.raises(TypeError):
+            a[:] = '0xff'
+        assert a == '0b10110'
+
+
+class TestStridedBytes:
+
+    def test_strided_bytes(self):
+        a = Bits('0x112233445566')
+        assert a.strided_bytes(2) == '0x113355'
+        assert a.strided_bytes(2, offset=1) == '0x224466'
+        assert a.strided_bytes(3, offset=2) == '0x3366'
+        assert a.strided_bytes(1) == a
+        assert a.strided_bytes(2, offset=10) == Bits()
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0x123').strided_bytes(2)
+        with pytest.raises(ValueError):
+            Bits('0x1234').strided_bytes(0)
+        with pytest.raises(ValueError):
+            Bits('0x1234').strided_bytes(1, offset=-1)
+
+
+class TestToHex:
+
+    def test_to_hex(self):
+        a = Bits('0xabc012')
+        assert a.to_hex() == 'abc012'
+        assert a.to_hex(upper=True) == 'ABC012'
+        assert Bits().to_hex() == ''
+
+    def test_bad_length(self):
+        with pytest.raises(bitformat.InterpretError):
+            Bits('0b101').to_hex()
+
+
+class TestInsertJoined:
+
+    def test_insert_joined(self):
+        a = Bits('0x00')
+        assert a.insert_joined(['0xa', '0b1', b'\xff'], 4) == '0x0a, 0b1, 0xff, 0x0'
+        assert a.insert_joined([], 4) == a
+        assert a.insert_joined(['0x1', '0x2'], -8) == '0x1200'
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0x00').insert_joined(['0x1'], 9)
+
+
+class TestLongestCommonSubstring:
+
+    def test_simple(self):
+        assert Bits('0b0011101').longest_common_substring('0b1111') == (2, 0, 3)
+        assert Bits('0b0011101').longest_common_substring('0b1110') == (2, 0, 4)
+        a = Bits('0x00ff12345600')
+        assert a.longest_common_substring('0b1, 0x123456, 0b1') == (15, 0, 25)
+
+    def test_identical(self):
+        a = Bits('0xdeadbeef')
+        assert a.longest_common_substring(a) == (0, 0, 32)
+
+    def test_no_match(self):
+        assert Bits('0x00').longest_common_substring('0xff') == (0, 0, 0)
+        assert Bits().longest_common_substring('0xff') == (0, 0, 0)
+
+    @pytest.mark.parametrize('seed', range(5))
+    def test_matches_brute_force(self, seed):
+        a = Bits.from_random(60, seed=seed)
+        b = Bits.from_random(40, seed=seed + 100)
+        i, j, length = a.longest_common_substring(b)
+        assert a[i: i + length] == b[j: j + length]
+        longest = max((n for n in range(1, 41) for q in range(41 - n)
+                       if a.find(b[q: q + n]) is not None), default=0)
+        assert length == longest
+
+    def test_long_inputs(self):
+        common = Bits.from_random(300, seed=7)
+        a = Bits.from_random(5000, seed=1) + common + Bits.from_random(3000, seed=2)
+        b = Bits.from_random(2000, seed=3) + common
+        i, j, length = a.longest_common_substring(b)
+        assert length >= 300
+        assert a[i: i + length] == b[j: j + length]
+
+
+class TestZeroInitialisation:
+
+    def test_zeros_are_zero(self):
+        for length in [1, 7, 8, 1000, 100001]:
+            a = Bits.zeros(length)
+            assert not a.any(1)
+            assert a.count(0) == length
+
+    def test_padding_is_zero(self):
+        a = Bits('0b1111111') + Bits.zeros(2)
+        assert a.to_bytes() == b'\xfe\x00'
+        assert Bits.from_positions([0], 3).to_bytes() == b'\x80'
+
+
+class TestStructUnpack:
+
+    def test_struct_unpack(self):
+        a = Bits('0x0001ffff')
+        assert a.struct_unpack('>Hh') == (1, -1)
+        assert a.struct_unpack('<I') == (0xffff0100,)
+        assert Bits.build('f32', 0.5).struct_unpack('>f') == (0.5,)
+
+    def test_errors(self):
+        import struct
+        with pytest.raises(ValueError):
+            Bits('0x123').struct_unpack('>H')
+        with pytest.raises(struct.error):
+            Bits('0x1234').struct_unpack('>I')
+
+
+class TestReinterpretIntWidth:
+
+    def test_widening(self):
+        assert Bits('u12=4095').reinterpret_int_width(16) == '0x0fff'
+        assert Bits('i4=-2').reinterpret_int_width(8, signed=True) == '0xfe'
+        assert Bits('0xe').reinterpret_int_width(8) == '0x0e'
+
+    def test_narrowing(self):
+        assert Bits('u16=200').reinterpret_int_width(8) == '0xc8'
+        assert Bits('i16=-100').reinterpret_int_width(8, signed=True) == 'i8=-100'
+        with pytest.raises(OverflowError):
+            Bits('u16=256').reinterpret_int_width(8)
+        with pytest.raises(OverflowError):
+            Bits('i16=128').reinterpret_int_width(8, signed=True)
+
+    @pytest.mark.parametrize('new_length', [0, -1])
+    def test_invalid_length(self, new_length):
+        with pytest.raises(ValueError, match='must be > 0'):
+            Bits('0x1').reinterpret_int_width(new_length)
+        with pytest.raises(ValueError, match='must be > 0'):
+            Bits('0x1').reinterpret_int_width(new_length, signed=True)
+
+    def test_empty(self):
+        with pytest.raises(ValueError):
+            Bits().reinterpret_int_width(8)
+
+
+class TestJoinMixedTypes:
+
+    def test_heterogeneous_sequence(self):
+        a = Bits.join(['0xf', b'\x00', Bits('0b1'), bytearray(b'\x01'), [1, 0, True, 0], io.BytesIO(b'\xff')])
+        assert a == '0xf, 0x00, 0b1, 0x01, 0b1010, 0xff'
+
+    def test_nested_generators(self):
+        a = Bits.join((x % 2 for x in range(n)) for n in range(1, 4))
+        assert a == '0b0, 0b01, 0b010'
+
+    def test_generator_of_bits(self):
+        a = Bits.join(Bits.build('u4', i) for i in range(3))
+        assert a == '0x012'
+
+    def test_bad_type(self):
+        with pytest.raises(TypeError):
+            Bits.join(['0x1', 4.5])
+
+
+class TestReductions:
+
+    def test_and_reduce(self):
+        assert Bits('0xff').and_reduce() is True
+        assert Bits('0xfe').and_reduce() is False
+
+    def test_or_reduce(self):
+        assert Bits('0x01').or_reduce() is True
+        assert Bits('0x00').or_reduce() is False
+
+    def test_xor_reduce(self):
+        assert Bits('0b1011').xor_reduce() is True
+        assert Bits('0b1001').xor_reduce() is False
+
+    def test_empty(self):
+        a = Bits()
+        assert (a.and_reduce(), a.or_reduce(), a.xor_reduce()) == (True, False, False)
+
+
+class TestDump:
+
+    def test_single_line(self):
+        a = Bits.from_bytes(b'hello!')
+        assert a.dump() == '00000000  68 65 6c 6c 6f 21                                |hello!|'
+
+    def test_multiple_lines(self):
+        a = Bits.from_bytes(b'\x00\x01ABCD\x7f')
+        assert a.dump(bytes_per_line=4) == ('00000000  00 01 41 42  |..AB|\n'
+                                            '00000004  43 44 7f     |CD.|')
+
+    def test_empty(self):
+        assert Bits().dump() == ''
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').dump()
+        with pytest.raises(ValueError):
+            Bits('0x00').dump(bytes_per_line=0)
+
+
+class TestOverwriteFromFile:
+
+    def test_overwrite_from_file(self):
+        import tempfile, os
+        with tempfile.TemporaryDirectory() as d:
+            path = os.path.join(d, 'patch.bin')
+            with open(path, 'wb') as f:
+                f.write(b'\xab\xcd')
+            a = Bits('0x00000000')
+            assert a.overwrite_from_file(path, 8) == '0x00abcd00'
+            assert a.overwrite_from_file(path, 0, length_bytes=1) == '0xab000000'
+            assert a.overwrite_from_file(path, -16) == '0x0000abcd'
+            with pytest.raises(ValueError):
+                a.overwrite_from_file(path, 4)
+            with pytest.raises(ValueError):
+                a.overwrite_from_file(path, 24)
+            with pytest.raises(OSError):
+                a.overwrite_from_file(path, 0, length_bytes=3)
+            with pytest.raises(OSError):
+                a.overwrite_from_file(os.path.join(d, 'missing.bin'), 0)
+
+
+class TestEqualsFloat:
+
+    def test_exact(self):
+        a = Bits.build('f64', 0.25)
+        assert a.equals_float(0.25)
+        assert not a.equals_float(0.2500001)
+        assert Bits.build('f16', -2.0).equals_float(-2)
+
+    def test_tolerance(self):
+        a = Bits.build('f32', 0.1)
+        assert not a.equals_float(0.1)
+        assert a.equals_float(0.1, rel_tol=1e-6)
+
+    def test_special_values(self):
+        assert Bits.build('f32', float('inf')).equals_float(float('inf'))
+        nan = Bits.build('f64', float('nan'))
+        assert not nan.equals_float(float('nan'))
+
+    def test_bad_length(self):
+        with pytest.raises(bitformat.InterpretError):
+            Bits('0x123').equals_float(1.0)
+
+
+class TestRepeatToLength:
+
+    def test_repeat_to_length(self):
+        a = Bits('0b110')
+        assert a.repeat_to_length(8) == '0b11011011'
+        assert a.repeat_to_length(6) == '0b110110'
+        assert a.repeat_to_length(2) == '0b11'
+        assert a.repeat_to_length(0) == Bits()
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits().repeat_to_length(4)
+        with pytest.raises(ValueError):
+            Bits('0b1').repeat_to_length(-1)
+
+
+class TestRangeFills:
+
+    def test_zero_range(self):
+        a = Bits('0xfff')
+        assert a.zero_range(4, 8) == '0xf0f'
+        assert a.zero_range(-4) == '0xff0'
+        assert a.zero_range() == '0x000'
+        assert a.zero_range(3, 3) == a
+
+    def test_one_range(self):
+        a = Bits('0x000')
+        assert a.one_range(4, 8) == '0x0f0'
+        assert a.one_range(end=1) == '0b1, 0b00000000000'
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0x0').zero_range(3, 2)
+        with pytest.raises(ValueError):
+            Bits('0x0').one_range(0, 5)
+
+
+class TestToBoolArray:
+
+    def test_to_bool_array(self):
+        np = pytest.importorskip('numpy')
+        a = Bits('0b1011, 0x0f')
+        b = a.to_bool_array()
+        assert b.dtype == np.bool_
+        assert b.tolist() == [True, False, True, True] + [False] * 4 + [True] * 4
+        assert len(Bits().to_bool_array()) == 0
+
+    def test_no_numpy(self):
+        import sys
+        saved = sys.modules.get('numpy')
+        sys.modules['numpy'] = None
+        try:
+            with pytest.raises(ImportError):
+                Bits('0b1').to_bool_array()
+        finally:
+            if saved is None:
+                del sys.modules['numpy']
+            else:
+                sys.modules['numpy'] = saved
+
+
+class TestMask:
+
+    def test_mask(self):
+        assert Bits.mask(8, (2, 5)) == '0b00111000'
+        assert Bits.mask(8, (2, 5), value=False) == '0b11000111'
+        assert Bits.mask(4) == '0xf'
+        assert Bits.mask(4, value=0) == '0x0'
+        assert Bits.mask(8, (-2, 8)) == '0x03'
+        assert Bits.mask(0) == Bits()
+
+    def test_mask_with_logical_ops(self):
+        a = Bits('0xabcd')
+        assert a & Bits.mask(16, (4, 8)) == '0x0b00'
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits.mask(8, (5, 2))
+        with pytest.raises(ValueError):
+            Bits.mask(8, (0, 9))
+
+
+class TestToInts:
+
+    @pytest.mark.parametrize("length", [1, 7, 64, 65, 200])
+    def test_to_uint(self, length):
+        assert Bits.ones(length).to_uint() == (1 << length) - 1
+        assert Bits.zeros(length).to_uint() == 0
+        a = Bits.zeros(length - 1) + '0b1'
+        assert a.to_uint() == 1
+
+    @pytest.mark.parametrize("length", [1, 7, 64, 65, 200])
+    def test_to_int(self, length):
+        assert Bits.ones(length).to_int() == -1
+        assert ('0b1' + Bits.zeros(length - 1)).to_int() == -(1 << (length - 1))
+        if length > 1:
+            assert ('0b0' + Bits.ones(length - 1)).to_int() == (1 << (length - 1)) - 1
+
+    def test_empty(self):
+        with pytest.raises(ValueError):
+            Bits().to_uint()
+        with pytest.raises(ValueError):
+            Bits().to_int()
+
+
+class TestRotateThroughCarry:
+
+    def test_rcl(self):
+        a = Bits('0b1000')
+        assert a.rcl(1) == (Bits('0b0000'), True)
+        assert a.rcl(1, carry_in=True) == (Bits('0b0001'), True)
+        assert a.rcl(2) == (Bits('0b0001'), False)
+        assert a.rcl(5) == (a, False)
+
+    def test_rcr(self):
+        a = Bits('0b0001')
+        assert a.rcr(1) == (Bits('0b0000'), True)
+        assert a.rcr(1, carry_in=True) == (Bits('0b1000'), True)
+        assert a.rcr(2) == (Bits('0b1000'), False)
+        assert a.rcr(5, carry_in=True) == (a, True)
+
+    def test_inverse(self):
+        a = Bits('0xa5')
+        b, c = a.rcl(3, carry_in=True)
+        assert b.rcr(3, carry_in=c) == (a, True)
+
+    def test_negative(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').rcl(-1)
+
+
+class TestReplace:
+
+    def test_overlapping_occurrences(self):
+        a = Bits('0b111')
+        assert a.replace('0b11', '0b0') == '0b01'
+        assert Bits('0b1111').replace('0b11', '0b0') == '0b00'
+
+    def test_new_contains_old(self):
+        a = Bits('0b101')
+        assert a.replace('0b1', '0b11') == '0b11011'
+        assert a.replace('0b1', '0b011', count=1) == '0b01101'
+
+
+class TestOccurrences:
+
+    def test_occurrences(self):
+        assert Bits('0b11111').occurrences('0b11') == 2
+        assert Bits('0b10101').occurrences('0b101') == 1
+        assert Bits('0x00ff00ff').occurrences('0xff') == 2
+        assert Bits('0x00').occurrences('0b1') == 0
+        assert Bits().occurrences('0b1') == 0
+
+    def test_bytealigned(self):
+        a = Bits('0x0ff0ff')
+        assert a.occurrences('0xff') == 2
+        assert a.occurrences('0xff', bytealigned=True) == 1
+
+    def test_empty_needle(self):
+        with pytest.raises(ValueError):
+            Bits('0x1').occurrences('')
+
+
+class TestGrayCode:
+
+    def test_word_widths(self):
+        a = Bits('0b0111, 0b1000')
+        assert a.to_gray(4) == '0b0100, 0b1100'
+        assert a.to_gray(8) == a.to_gray()
+
+    @pytest.mark.parametrize("word_bits", [1, 3, 4, 8, 12, 24])
+    def test_round_trip(self, word_bits):
+        a = Bits('0x5a3c96f0e1d2')[:48 // word_bits * word_bits]
+        assert a.to_gray(word_bits).from_gray(word_bits) == a
+        assert a.from_gray(word_bits).to_gray(word_bits) == a
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0x12').to_gray(3)
+        with pytest.raises(ValueError):
+            Bits('0x12').from_gray(0)
+
+    def test_four_bit_sequence(self):
+        gray = ['0000', '0001', '0011', '0010', '0110', '0111', '0101', '0100',
+                '1100', '1101', '1111', '1110', '1010', '1011', '1001', '1000']
+        for i, g in enumerate(gray):
+            assert Bits.from_uint(i, 4).to_gray() == '0b' + g
+            assert Bits('0b' + g).from_gray().to_uint() == i
+
+    def test_odd_lengths(self):
+        assert Bits('0b1').to_gray() == '0b1'
+        assert Bits('0b101').to_gray() == '0b111'
+        assert Bits('0b111').from_gray() == '0b101'
+        assert Bits().to_gray() == Bits()
+
+
+class TestCountRange:
+
+    def test_count_range(self):
+        a = Bits('0b1110_0011_0101_1111')
+        assert a.count(1) == 11
+        assert a.count(1, 2, 13) == 6
+        assert a.count(0, 2, 13) == 5
+        assert a.count(1, start=5) == 8
+        assert a.count(1, end=-4) == 7
+        assert a.count(1, 7, 7) == 0
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0x0').count(1, 3, 2)
+        with pytest.raises(ValueError):
+            Bits('0x0').count(1, 0, 5)
+
+
+@pytest.mark.skipif(sys.version_info < (3, 12), reason="The buffer protocol needs Python 3.12 or later.")
+class TestBufferProtocol:
+
+    def test_memoryview(self):
+        m = memoryview(Bits('0x0102ff'))
+        assert m.readonly
+        assert m.tobytes() == b'\x01\x02\xff'
+        assert bytearray(Bits('0xabcd')) == bytearray(b'\xab\xcd')
+
+    def test_not_byte_aligned(self):
+        with pytest.raises(BufferError):
+            memoryview(Bits('0x123'))
+        with pytest.raises(BufferError):
+            bytearray(Bits('0x123'))
+        # bytes() uses __bytes__, which pads rather than using the buffer
+        assert bytes(Bits('0x123')) == b'\x12\x30'
+
+
+class TestRegisterLiteralPrefix:
+
+    def setup_method(self):
+        self.literal_bit_funcs = dict(bitstore_helpers.literal_bit_funcs)
+        self.literal_re = bitstore_helpers.LITERAL_RE
+
+    def teardown_method(self):
+        bitstore_helpers.literal_bit_funcs.clear()
+        bitstore_helpers.literal_bit_funcs.update(self.literal_bit_funcs)
+        bitstore_helpers.LITERAL_RE = self.literal_re
+        bitstore_helpers.tokenparser.cache_clear()
+        bitstore_helpers.str_to_bitstore.cache_clear()
+
+    def test_quaternary(self):
+        bitformat.register_literal_prefix('0q', 2, {'0': 0, '1': 1, '2': 2, '3': 3})
+        assert Bits('0q0123') == '0x1b'
+        assert Bits('0Q 3_3, 0b1') == '0b11111'
+        with pytest.raises(ValueError):
+            Bits('0q4')
+
+    def test_dna(self):
+        bitformat.register_literal_prefix('0d', 2, {'a': 0, 'c': 1, 'g': 2, 't': 3})
+        assert Bits('0dgatc') == '0b10001101'
+        assert Bits('0x1, 0dt') == '0b000111'
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            bitformat.register_literal_prefix('0x', 4, {})
+        with pytest.raises(ValueError):
+            bitformat.register_literal_prefix('q', 2, {'0': 0})
+        with pytest.raises(ValueError):
+            bitformat.register_literal_prefix('0r', 1, {'2': 2})
+
+    def test_registration_is_undone(self):
+        bitformat.register_literal_prefix('0q', 2, {'0': 0, '1': 1, '2': 2, '3': 3})
+        assert Bits('0q1') == '0b01'
+        self.teardown_method()
+        with pytest.raises(ValueError):
+            Bits('0q1')
+
+
+class TestEquality:
+
+    def test_different_lengths(self):
+        a = Bits('0x00')
+        assert a != Bits('0x000')
+        assert a != '0b0000000'
+        assert not a == Bits()
+        assert a == Bits.zeros(8)
+
+
+class TestSplitWhere:
+
+    def test_split_where(self):
+        a = Bits('0xaffaff0')
+        assert a.split_where('0xff') == ['0xa', '0xa', '0x0']
+        assert Bits('0b1101011').split_where('0b0') == ['0b11', '0b1', '0b11']
+        assert Bits('0xff0ff').split_where('0xff') == [Bits(), '0x0', Bits()]
+        assert Bits('0x123').split_where('0xf') == ['0x123']
+        assert Bits().split_where('0b1') == [Bits()]
+
+    def test_keep_delimiter(self):
+        a = Bits.from_bytes(b'ab\ncd\n')
+        assert a.split_where('0x0a', keep_delimiter=True) == [Bits.from_bytes(b'ab\n'), Bits.from_bytes(b'cd\n')]
+        b = Bits.from_bytes(b'ab\ncd')
+        assert b.split_where('0x0a', keep_delimiter=True) == [Bits.from_bytes(b'ab\n'), Bits.from_bytes(b'cd')]
+
+    def test_non_overlapping(self):
+        assert Bits('0b01110').split_where('0b11') == ['0b0', '0b10']
+
+    def test_bytealigned(self):
+        a = Bits('0x0ff0ff')
+        assert a.split_where('0xff', bytealigned=True) == ['0x0ff0', Bits()]
+
+    def test_empty_delimiter(self):
+        with pytest.raises(ValueError):
+            Bits('0x1').split_where('')
+
+
+class TestCountOccurrences:
+
+    def test_self_overlapping(self):
+        a = Bits('0b1111')
+        assert a.count_occurrences('0b11') == 2
+        assert a.count_occurrences('0b11', overlapping=True) == 3
+
+    def test_bytealigned(self):
+        a = Bits('0xfff0ff')
+        assert a.count_occurrences('0xff', overlapping=True) == 6
+        assert a.count_occurrences('0xff', bytealigned=True, overlapping=True) == 2
+
+    def test_empty(self):
+        assert Bits().count_occurrences('0b1') == 0
+        assert Bits().count_occurrences('0b1', overlapping=True) == 0
+        with pytest.raises(ValueError):
+            Bits('0b1').count_occurrences('', overlapping=True)
+        with pytest.raises(ValueError):
+            Bits('0b1').count_occurrences('')
+
+
+class TestApplyEdits:
+
+    def test_positions_refer_to_original(self):
+        a = Bits('0x0000')
+        b = a.apply_edits([('overwrite', 8, '0xff'), ('insert', 4, '0b1'), ('delete', 0, 4)])
+        assert b == Bits('0b1, 0x0ff')
+        assert a == Bits('0x0000')
+
+    def test_insert_order_preserved(self):
+        a = Bits('0b00')
+        b = a.apply_edits([('insert', 1, '0b1'), ('insert', 1, '0b11'), ('delete', 1, 2)])
+        assert b == Bits('0b0111')
+
+    def test_empty_edits(self):
+        a = Bits('0xabc')
+        assert a.apply_edits([]) == a
+
+    @pytest.mark.parametrize('edits', [
+        [('insert', 17, '0b1')],
+        [('delete', 4, 20)],
+        [('overwrite', 10, '0xff')],
+        [('delete', 0, 8), ('overwrite', 4, '0b1')],
+        [('remove', 0, 1)],
+    ])
+    def test_errors(self, edits):
+        with pytest.raises(ValueError):
+            Bits('0x0000').apply_edits(edits)
+
+
+class TestSplit:
+
+    def test_split(self):
+        a = Bits('0xaffaff0')
+        assert list(a.split('0xff')) == [Bits('0xa'), Bits('0xa'), Bits('0x0')]
+
+    def test_split_is_lazy(self):
+        s = Bits('0b1101011').split('0b0')
+        assert next(s) == Bits('0b11')
+        assert next(s) == Bits('0b1')
+
+    def test_delimiter_at_ends(self):
+        a = Bits('0b0110')
+        assert list(a.split('0b0')) == [Bits(), Bits('0b11'), Bits()]
+        assert list(Bits().split('0b1')) == [Bits()]
+
+    def test_count(self):
+        a = Bits('0b1101011')
+        assert list(a.split('0b0', count=2)) == [Bits('0b11'), Bits('0b1')]
+        assert list(a.split('0b0', count=0)) == []
+        assert len(list(a.split('0b0', count=10))) == 3
+
+    def test_bytealigned(self):
+        a = Bits('0x0f0f00')
+        assert list(a.split('0x0', bytealigned=True)) == [Bits(), Bits('0xf'), Bits('0xf'), Bits('0x0')]
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            list(Bits('0b1').split(''))
+        with pytest.raises(ValueError):
+            list(Bits('0b1').split('0b1', count=-1))
+
+
+class TestFromIntegers:
+
+    def test_from_uint(self):
+        assert Bits.from_uint(10, 8) == '0x0a'
+        assert Bits.from_uint(0, 1) == '0b0'
+        big = (1 << 100) + 5
+        a = Bits.from_uint(big, 101)
+        assert len(a) == 101
+        assert a.to_uint() == big
+
+    def test_from_int(self):
+        assert Bits.from_int(-1, 12) == '0xfff'
+        assert Bits.from_int(-128, 8) == '0x80'
+        assert Bits.from_int(127, 8) == '0x7f'
+        a = Bits.from_int(-(1 << 99), 100)
+        assert a.to_int() == -(1 << 99)
+
+    @pytest.mark.parametrize('value, length', [(-1, 8), (256, 8), (1 << 64, 64), (1, 0)])
+    def test_from_uint_errors(self, value, length):
+        with pytest.raises(ValueError):
+            Bits.from_uint(value, length)
+
+    @pytest.mark.parametrize('value, length', [(128, 8), (-129, 8), (1 << 99, 100), (0, -1)])
+    def test_from_int_errors(self, value, length):
+        with pytest.raises(ValueError):
+            Bits.from_int(value, length)
+
+
+class TestIntConversion:
+
+    def test_int(self):
+        assert int(Bits('0xff')) == 255
+        assert int(Bits('0b1')) == 1
+        assert int(Bits('0x800')) == Bits('0x800').to_uint()
+
+    def test_long(self):
+        a = Bits.from_uint((1 << 200) - 1, 200)
+        assert int(a) == (1 << 200) - 1
+
+    def test_empty(self):
+        with pytest.raises(ValueError, match='zero length'):
+            _ = int(Bits())
+
+
+class TestRotateEmpty:
+
+    def test_empty_bits(self):
+        assert Bits().rol(3) == Bits()
+        assert Bits().ror(3) == Bits()
+
+    def test_empty_slice(self):
+        a = Bits('0b1100')
+        assert a.rol(1, 2, 2) == a
+        assert a.ror(5, start=4) == a
+
+    def test_negative_still_raises(self):
+        with pytest.raises(ValueError):
+            Bits().rol(-1)
+        with pytest.raises(ValueError):
+            Bits().ror(-1)
+
+
+class TestFindBetween:
+
+    def test_find_between(self):
+        a = Bits('0x0a12b0a3b')
+        assert list(a.find_between('0xa', '0xb')) == [Bits('0x12'), Bits('0x3')]
+
+    def test_same_marker(self):
+        a = Bits('0x7e1234_7e_7e56_7e')
+        assert list(a.find_between('0x7e', '0x7e', bytealigned=True)) == [Bits('0x1234'), Bits('0x56')]
+
+    def test_empty_section_and_unterminated(self):
+        a = Bits('0b0110, 0b0111')
+        assert list(a.find_between('0b0', '0b1')) == [Bits(), Bits('0b0')]
+        assert list(Bits('0xa12').find_between('0xa', '0xb')) == []
+        assert list(Bits().find_between('0xa', '0xb')) == []
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            list(Bits('0x1').find_between('', '0b1'))
+        with pytest.raises(ValueError):
+            list(Bits('0x1').find_between('0b1', ''))
+
+
+class TestFormat:
+
+    def test_format_specs(self):
+        a = Bits('0x1ab')
+        assert f'{a:x}' == '1ab'
+        assert f'{a:X}' == '1AB'
+        assert f'{a:b}' == '000110101011'
+        assert f'{a:o}' == '0653'
+        assert format(a, '') == str(a)
+        assert f'{a}' == str(a)
+
+    def test_odd_lengths(self):
+        assert f"{Bits('0b101'):b}" == '101'
+        with pytest.raises(ValueError):
+            _ = f"{Bits('0b101'):x}"
+        with pytest.raises(ValueError):
+            _ = f"{Bits('0b1011'):o}"
+
+    def test_unknown_spec(self):
+        with pytest.raises(ValueError):
+            _ = f"{Bits('0xf'):d}"
+
+
+class TestInPlaceAdd:
+
+    def test_self_append(self):
+        a = Bits('0b101')
+        b = a
+        a += a
+        assert a == '0b101101'
+        assert len(a) == 6
+        assert b == '0b101'
+
+    def test_append_and_prepend_self(self):
+        a = Bits('0b110')
+        assert a.append(a) == '0b110110'
+        assert a.prepend(a) == '0b110110'
+        assert a.insert(a, 1) == '0b111010'
+        assert a == '0b110'
+
+
+class TestByteOrder:
+
+    def test_uint(self):
+        a = Bits('0x0102')
+        assert a.to_uint() == 0x0102
+        assert a.to_uint('big') == 0x0102
+        assert a.to_uint('little') == 0x0201
+
+    def test_int(self):
+        a = Bits('0x00ff')
+        assert a.to_int('big') == 255
+        assert a.to_int('little') == -256
+        assert Bits('0xfe').to_int('little') == -2
+
+    def test_long(self):
+        a = Bits.from_bytes(bytes(range(1, 17)))
+        assert a.to_uint('little') == int.from_bytes(bytes(range(1, 17)), 'little')
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0x123').to_uint('little')
+        with pytest.raises(ValueError):
+            Bits('0x12').to_int('middle')
+        with pytest.raises(ValueError):
+            Bits().to_uint('little')
+
+
+class TestRemoveRange:
+
+    def test_remove_range(self):
+        a = Bits('0b110011')
+        assert a.remove_range(2, 4) == (Bits('0xf'), Bits('0b00'))
+        assert a.remove_range(4) == (Bits('0xc'), Bits('0b11'))
+        assert a.remove_range(0, 0) == (a, Bits())
+        assert a == '0b110011'
+
+    def test_negative_positions(self):
+        a = Bits('0x1234')
+        assert a.remove_range(-8) == (Bits('0x12'), Bits('0x34'))
+        assert a.remove_range(-12, -4) == (Bits('0x14'), Bits('0x23'))
+
+    def test_reinsert(self):
+        a = Bits('0xabcd')
+        rest, removed = a.remove_range(4, 8)
+        assert rest.insert(removed, 12) == '0xacdb'
+
+    @pytest.mark.parametrize('start, end', [(17, None), (-17, None), (4, 2), (0, 17)])
+    def test_errors(self, start, end):
+        with pytest.raises(ValueError):
+            Bits('0x1234').remove_range(start, end)
+
+
+class TestSetMask:
+
+    def test_set_mask(self):
+        a = Bits('0b1100')
+        assert a.set_mask(1, '0b0101') == '0b1101'
+        assert a.set_mask(0, '0b0101') == '0b1000'
+        assert a.set_mask(True, Bits.zeros(4)) == a
+        assert a.set_mask(False, Bits.ones(4)) == '0b0000'
+
+    def test_large_sparse(self):
+        mask = Bits.from_positions([3, 1000, 99999], 100000)
+        a = Bits.zeros(100000).set_mask(1, mask)
+        assert a.set_bits() == [3, 1000, 99999]
+
+    def test_length_mismatch(self):
+        with pytest.raises(ValueError):
+            Bits('0b1100').set_mask(1, '0b1')
+
+
+class TestToList:
+
+    def test_tolist(self):
+        a = Bits('0b1011')
+        x = a.tolist()
+        assert x == [True, False, True, True]
+        assert all(type(b) is bool for b in x)
+        assert x == list(a)
+
+    def test_empty(self):
+        assert Bits().tolist() == []
+
+
+class TestBase64:
+
+    @pytest.mark.parametrize('data', [b'', b'a', b'ab', b'abc', bytes(range(256))])
+    def test_round_trip(self, data):
+        a = Bits.from_bytes(data)
+        assert Bits.from_base64(a.to_base64()) == a
+        assert Bits.from_base32(a.to_base32()) == a
+
+    def test_known_values(self):
+        assert Bits('0x616263').to_base64() == 'YWJj'
+        assert Bits.from_base64('YWI=') == '0x6162'
+        assert Bits('0x6162').to_base32() == 'MFRA===='
+        assert Bits.from_base32(b'MFRA====') == '0x6162'
+
+    def test_padding_to_bytes(self):
+        assert Bits('0b1').to_base64() == Bits('0x80').to_base64()
+
+    def test_invalid(self):
+        with pytest.raises(ValueError):
+            Bits.from_base64('YW*j')
+        with pytest.raises(ValueError):
+            Bits.from_base32('MFRA!===')
+
+
+class TestMultiplication:
+
+    @pytest.mark.parametrize('n', [0, 1, 2, 3, 7, 8, 100])
+    def test_matches_join(self, n):
+        a = Bits('0b101')
+        assert a * n == Bits.join([a] * n)
+        assert n * a == a * n
+
+    def test_source_unchanged(self):
+        a = Bits('0xf')
+        b = a * 4
+        assert b == '0xffff'
+        assert a == '0xf'
+        assert Bits() * 5 == Bits()
+
+
+class TestHammingDistance:
+
+    def test_hamming_distance(self):
+        assert Bits('0b1100').hamming_distance('0b1010') == 2
+        assert Bits('0xff00').hamming_distance(Bits('0x0f0f')) == 8
+
+    def test_equal_and_all_different(self):
+        a = Bits('0x5a3c9')
+        assert a.hamming_distance(a) == 0
+        assert a.hamming_distance(~a) == len(a)
+        assert Bits().hamming_distance(Bits()) == 0
+
+    def test_length_mismatch(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').hamming_distance('0b10')
+
+
+class TestReverseBytes:
+
+    def test_reverse_bytes(self):
+        assert Bits('0x112233').reverse_bytes() == '0x332211'
+        assert Bits('0x01').reverse_bytes() == '0x01'
+        assert Bits().reverse_bytes() == Bits()
+
+    def test_differs_from_reverse(self):
+        a = Bits('0x0180')
+        assert a.reverse_bytes() == '0x8001'
+        assert a.reverse() == '0x0180'
+
+    def test_not_whole_bytes(self):
+        with pytest.raises(ValueError):
+            Bits('0x123').reverse_bytes()
+
+
+class TestStrip:
+
+    def test_strip_zeros(self):
+        a = Bits('0b000101000')
+        assert a.lstrip() == '0b101000'
+        assert a.rstrip() == '0b000101'
+        assert a.strip() == '0b101'
+
+    def test_strip_ones(self):
+        a = Bits('0b1101011')
+        assert a.lstrip(True) == '0b01011'
+        assert a.rstrip(1) == '0b11010'
+        assert a.strip(True) == '0b010'
+
+    def test_all_stripped(self):
+        for b in (Bits.zeros(10), Bits()):
+            assert b.lstrip() == Bits()
+            assert b.rstrip() == Bits()
+            assert b.strip() == Bits()
+        assert Bits.ones(5).strip(True) == Bits()
+
+    def test_nothing_to_strip(self):
+        a = Bits('0b101')
+        assert a.strip() == a
+
+
+class TestWindows:
+
+    def test_windows(self):
+        w = list(Bits('0b10110').windows(2))
+        assert len(w) == 4
+        assert w == ['0b10', '0b01', '0b11', '0b10']
+
+    def test_sizes(self):
+        a = Bits('0b101')
+        assert list(a.windows(3)) == [a]
+        assert list(a.windows(4)) == []
+        assert list(a.windows(1)) == ['0b1', '0b0', '0b1']
+        assert list(Bits().windows(1)) == []
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            list(Bits('0b1').windows(0))
+
+
+class TestBools:
+
+    def test_round_trip(self):
+        values = [True, False, False, True, True]
+        a = Bits.from_bools(values)
+        assert a == '0b10011'
+        assert a.to_bools() == values
+        assert a.tolist() == a.to_bools()
+
+    def test_truthy_values(self):
+        assert Bits.from_bools([5, 0, 'a', '', None, [0]]) == '0b101001'
+        assert Bits.from_bools(x > 2 for x in range(5)) == '0b00011'
+        assert Bits.from_bools([]) == Bits()
+
+
+class TestFloatConversion:
+
+    @pytest.mark.parametrize('length', [16, 32, 64])
+    @pytest.mark.parametrize('value', [0.0, -0.0, 1.0, -2.5, 0.125, float('inf'), float('-inf')])
+    def test_round_trip(self, length, value):
+        a = Bits.from_float(value, length)
+        assert len(a) == length
+        f = a.to_float()
+        assert f == value
+        assert math.copysign(1.0, f) == math.copysign(1.0, value)
+
+    @pytest.mark.parametrize('length', [16, 32, 64])
+    def test_nan(self, length):
+        assert math.isnan(Bits.from_float(float('nan'), length).to_float())
+
+    def test_known_values(self):
+        assert Bits.from_float(1.0, 16) == '0x3c00'
+        assert Bits.from_float(1.0, 32) == '0x3f800000'
+        assert Bits.from_float(-2.0, 64) == '0xc000000000000000'
+        assert Bits.from_float(1e10, 16).to_float() == float('inf')
+
+    @pytest.mark.parametrize('length, smallest', [(16, 2.0 ** -24), (32, 2.0 ** -149), (64, 5e-324)])
+    def test_subnormals(self, length, smallest):
+        a = Bits.from_float(smallest, length)
+        assert a == Bits.from_uint(1, length)
+        assert a.to_float() == smallest
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits.from_float(1.0, 8)
+        with pytest.raises(ValueError):
+            Bits('0x123').to_float()
+        with pytest.raises(ValueError):
+            Bits().to_float()
+
+
+class TestMaskIndexing:
+
+    def test_mask(self):
+        a = Bits('0b110010')
+        assert a[Bits('0b101011')] == '0b1010'
+        assert a[Bits.zeros(6)] == Bits()
+        assert a[Bits.ones(6)] == a
+        assert a[~Bits('0b101011')] == '0b10'
+
+    def test_length_mismatch(self):
+        with pytest.raises(ValueError):
+            _ = Bits('0b1100')[Bits('0b11')]
+
+    def test_other_keys_unchanged(self):
+        a = Bits('0b1100')
+        assert a[1] is True
+        assert a[1:3] == '0b10'
+        with pytest.raises(TypeError):
+            _ = a['0b1111']
+
+
+class TestResize:
+
+    def test_grow_and_shrink(self):
+        a = Bits('0b1011')
+        b = a.resize(10)
+        assert b == '0b1011000000'
+        c = a.resize(10, value=True)
+        assert c == '0b1011111111'
+        assert c.resize(4) == a
+        assert a.resize(4) == a
+        assert a.resize(0) == Bits()
+
+    def test_truncate(self):
+        a = Bits('0b1011')
+        assert a.truncate(2) == '0b10'
+        assert a.truncate(4) == a
+        assert a.truncate(0) == Bits()
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').resize(-1)
+        with pytest.raises(ValueError):
+            Bits('0b1').truncate(2)
+        with pytest.raises(ValueError):
+            Bits('0b1').truncate(-1)
+
+
+class TestIndex:
+
+    def test_index(self):
+        a = Bits('0x0f0f')
+        assert a.index('0xf') == 4
+        assert a.index('0xf', start=5) == 12
+        assert a.rindex('0xf') == 12
+        assert a.rindex('0xf', end=12) == 4
+        assert a.index('0x0f', bytealigned=True) == 0
+
+    def test_not_found(self):
+        a = Bits('0x0f0f')
+        with pytest.raises(ValueError, match=re.escape("Bits('0xa')")):
+            a.index('0xa')
+        with pytest.raises(ValueError, match=re.escape("Bits('0b11111')")):
+            a.rindex('0b11111')
+        assert a.find('0xa') is None
+        assert a.rfind('0xa') is None
+
+    def test_empty(self):
+        with pytest.raises(ValueError):
+            Bits('0x1').index('')
+
+
+class TestJoinWithSeparator:
+
+    def test_separator(self):
+        assert Bits.join(['0x1', '0x2', '0x3'], sep='0xff') == '0x1ff2ff3'
+        assert Bits.join([Bits('0b1'), '0b0'], sep=Bits()) == '0b10'
+        assert Bits.join((b for b in ['0x1', '0x2']), sep='0b0') == '0x1, 0b0, 0x2'
+
+    def test_empty_and_single(self):
+        assert Bits.join([], sep='0xff') == Bits()
+        assert Bits.join(['0xab'], sep='0xff') == '0xab'
+        assert Bits.join([Bits(), Bits()], sep='0xff') == '0xff'
+
+
+class TestLeadingTrailingZeros:
+
+    def test_counts(self):
+        a = Bits('0b00010100')
+        assert a.leading_zeros() == 3
+        assert a.trailing_zeros() == 2
+        assert a.significant_bits() == 5
+
+    def test_all_zeros(self):
+        for a in (Bits.zeros(9), Bits()):
+            assert a.leading_zeros() == len(a)
+            assert a.trailing_zeros() == len(a)
+            assert a.significant_bits() == 0
+
+    @pytest.mark.parametrize('value', [1, 2, 3, 255, 256, 12345, (1 << 70) + 1])
+    def test_matches_bit_length(self, value):
+        a = Bits.from_uint(value, 80)
+        assert a.significant_bits() == value.bit_length()
+        assert a.trailing_zeros() == (value & -value).bit_length() - 1
+
+
+class TestMatmul:
+
+    def test_matmul(self):
+        a = Bits('0x1')
+        assert a @ '0x2' == '0x12'
+        assert a @ Bits('0b1') == a + '0b1'
+        assert '0x2' @ a == '0x21'
+        assert b'\xff' @ a == '0xff1'
+
+    def test_chaining(self):
+        packet = Bits('0x7e') @ Bits.from_uint(3, 8) @ '0xabcdef'
+        assert packet == '0x7e03abcdef'
+
+
+class TestFromRandom:
+
+    def test_lengths(self):
+        assert Bits.from_random(0) == Bits()
+        assert len(Bits.from_random(1000)) == 1000
+        assert len(Bits.from_random(13, p=0.1)) == 13
+
+    @pytest.mark.parametrize('p', [0.5, 0.3])
+    def test_seed_is_reproducible(self, p):
+        assert Bits.from_random(500, seed=42, p=p) == Bits.from_random(500, seed=42, p=p)
+        assert Bits.from_random(500, seed=42, p=p) != Bits.from_random(500, seed=43, p=p)
+
+    @pytest.mark.parametrize('p', [0.05, 0.5, 0.9])
+    def test_probability(self, p):
+        a = Bits.from_random(20000, seed=1, p=p)
+        assert abs(a.count(1) / len(a) - p) < 0.02
+
+    def test_extremes(self):
+        assert Bits.from_random(100, p=0.0) == Bits.zeros(100)
+        assert Bits.from_random(100, p=1.0) == Bits.ones(100)
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits.from_random(-1)
+        with pytest.raises(ValueError):
+            Bits.from_random(10, p=1.5)
+        with pytest.raises(ValueError):
+            Bits.from_random(10, p=-0.1)
+
+
+class TestConstantTimeEq:
+
+    def test_equal(self):
+        a = Bits('0x1234567890abcdef')
+        assert a.constant_time_eq(Bits('0x1234567890abcdef'))
+        assert a.constant_time_eq('0x1234567890abcdef')
+        assert Bits().constant_time_eq(Bits())
+        assert Bits('0b101').constant_time_eq('0b101')
+
+    def test_not_equal(self):
+        a = Bits('0x1234567890abcdef')
+        assert not a.constant_time_eq('0x1234567890abcdee')
+        assert not a.constant_time_eq('0x1234567890abcd')
+        assert not Bits('0b101').constant_time_eq('0b100')
+        assert not Bits('0b10').constant_time_eq('0b100')
+
+
+class TestShiftModes:
+
+    def test_logical(self):
+        a = Bits('0b10010011')
+        assert a.shift(2) == '0b01001100' == a << 2
+        assert a.shift(-2) == '0b00100100' == a >> 2
+        assert a.shift(0) == a
+        assert a.shift(-20) == Bits.zeros(8)
+
+    def test_arithmetic(self):
+        a = Bits('0b10010000')
+        assert a.shift(-2, mode='arithmetic') == '0b11100100'
+        assert a.shift(-2, mode='arithmetic').to_int() == a.to_int() >> 2
+        assert a.shift(-20, mode='arithmetic') == Bits.ones(8)
+        assert a.shift(1, mode='arithmetic') == '0b00100000'
+        b = Bits('0b01010000')
+        assert b.shift(-3, mode='arithmetic') == '0b00001010'
+
+    def test_circular(self):
+        a = Bits('0b10010011')
+        assert a.shift(3, mode='circular') == '0b10011100' == a.rol(3)
+        assert a.shift(-3, mode='circular') == '0b01110010' == a.ror(3)
+        assert Bits().shift(2, mode='circular') == Bits()
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').shift(1, mode='sideways')
+        with pytest.raises(ValueError):
+            Bits().shift(1)
+
+
+class TestPadding:
+
+    def test_pad_zeros(self):
+        a = Bits('0b101')
+        assert a.pad_left(8) == '0b00000101'
+        assert a.pad_right(8) == '0b10100000'
+
+    def test_pad_ones(self):
+        a = Bits('0b101')
+        assert a.pad_left(8, value=True) == '0b11111101'
+        assert a.pad_right(8, value=True) == '0b10111111'
+
+    def test_already_long_enough(self):
+        a = Bits('0b101')
+        assert a.pad_left(3) == a
+        assert a.pad_right(2) == a
+        assert a.pad_left(-1) == a
+        assert Bits().pad_right(2) == '0b00'
+
+
+class TestToBytesPadding:
+
+    def test_left_and_right(self):
+        a = Bits('0b1, 0xff')
+        assert a.to_bytes() == b'\xff\x80'
+        assert a.to_bytes(pad='right') == b'\xff\x80'
+        assert a.to_bytes(pad='left') == b'\x01\xff'
+        assert int.from_bytes(a.to_bytes(pad='left'), 'big') == a.to_uint()
+
+    def test_fill(self):
+        a = Bits('0b1, 0x00')
+        assert a.to_bytes(fill=True) == b'\x80\x7f'
+        assert a.to_bytes(pad='left', fill=True) == b'\xff\x00'
+
+    def test_aligned_unchanged(self):
+        a = Bits('0xabcd')
+        assert a.to_bytes(pad='left', fill=True) == a.to_bytes() == b'\xab\xcd'
+        assert Bits().to_bytes(pad='left') == b''
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').to_bytes(pad='middle')
+
+
+class TestFindAllPositions:
+
+    @pytest.mark.parametrize('needle', ['0b1', '0b101', '0x0f', '0b0000'])
+    @pytest.mark.parametrize('bytealigned', [False, True])
+    def test_matches_find_all(self, needle, bytealigned):
+        a = Bits('0x0f0f5a5a00ff0101')
+        assert a.find_all_positions(needle, bytealigned=bytealigned) == list(a.find_all(needle, bytealigned=bytealigned))
+        assert (a.find_all_positions(needle, start=5, end=50, bytealigned=bytealigned) ==
+                list(a.find_all(needle, start=5, end=50, bytealigned=bytealigned)))
+
+    def test_overlapping(self):
+        assert Bits('0b1111').find_all_positions('0b11') == [0, 1, 2]
+        assert Bits().find_all_positions('0b1') == []
+
+    def test_end_past_length(self):
+        a = Bits('0b0110110')
+        assert a.find_all_positions('0b11', end=100) == [1, 4]
+        assert a.find_all_positions('0b11', start=2, end=len(a) + 1) == a.find_all_positions('0b11', start=2)
+        assert a.find_all_positions('0b11', end=100) == [a.find('0b11', end=100), 4]
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0b1').find_all_positions('')
+        with pytest.raises(ValueError):
+            Bits('0b1').find_all_positions('0b1', start=2)
+
+
+class TestSetAll:
+
+    def test_whole(self):
+        a = Bits.from_random(1000, seed=3)
+        b = a.setall(1)
+        assert b.count(1) == 1000
+        c = b.setall(0)
+        assert c.count(1) == 0
+        assert a == Bits.from_random(1000, seed=3)
+
+    def test_range(self):
+        a = Bits('0x0f')
+        assert a.setall(1, 2, 6) == '0x3f'
+        assert a.setall(0, start=-2) == '0x0c'
+        assert a.setall(True, 3, 3) == a
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits('0x0f').setall(1, 4, 2)
+        with pytest.raises(ValueError):
+            Bits('0x0f').setall(1, end=9)
+
+
+class TestReversed:
+
+    def test_reversed(self):
+        assert list(reversed(Bits('0b1000'))) == [False, False, False, True]
+        a = Bits('0x5a3')
+        assert list(reversed(a)) == list(a)[::-1]
+        assert list(reversed(Bits())) == []
+
+    def test_does_not_copy(self):
+        r = reversed(Bits('0b01'))
+        assert next(r) is True
+        assert next(r) is False
+
+
+class TestInterleave:
+
+    def test_interleave(self):
+        assert Bits.interleave('0b11', '0b00') == '0b1010'
+        assert Bits.interleave('0b1100', '0b0101') == '0b10110001'
+        assert Bits.interleave(Bits(), Bits()) == Bits()
+
+    def test_round_trip(self):
+        a = Bits('0x5a3c9')
+        b = Bits('0xf0e1d')
+        c = Bits.interleave(a, b)
+        assert len(c) == 40
+        assert c.deinterleave() == (a, b)
+
+    def test_odd_length_deinterleave(self):
+        assert Bits('0b10101').deinterleave() == (Bits('0b111'), Bits('0b00'))
+
+    def test_length_mismatch(self):
+        with pytest.raises(ValueError):
+            Bits.interleave('0b1', '0b10')
+
+
+class TestRunLength:
+
+    def test_encode(self):
+        assert Bits('0b000111100').run_length_encode() == [(False, 3), (True, 4), (False, 2)]
+        assert Bits('0b1').run_length_encode() == [(True, 1)]
+        assert Bits.ones(1000).run_length_encode() == [(True, 1000)]
+        assert Bits().run_length_encode() == []
+
+    @pytest.mark.parametrize('s', ['0b000111100', '0b1', '0b10101', '0x0000ffff0f', ''])
+    def test_round_trip(self, s):
+        a = Bits(s)
+        assert Bits.from_run_length(a.run_length_encode()) == a
+
+    def test_from_run_length(self):
+        assert Bits.from_run_length([(0, 3), (1, 4)]) == '0b0001111'
+        assert Bits.from_run_length([(1, 0), (True, 2), (0, 1), (0, 1)]) == '0b1100'
+        assert Bits.from_run_length([]) == Bits()
+        with pytest.raises(ValueError):
+            Bits.from_run_length([(1, -1)])
+
+
+class TestEqualityWithOtherTypes:
+
+    def test_not_implemented(self):
+        a = Bits('0x0')
+        assert a.__eq__(None) is NotImplemented
+        assert a.__eq__(object()) is NotImplemented
+        assert a.__ne__(1.5) is NotImplemented
+
+    def test_operators(self):
+        a = Bits('0x0')
+        assert not (a == None)
+        assert a != None
+        assert not (a == object())
+        assert a != object()
+        assert a == '0x0'
+        assert a != '0x1'
+
+    def test_reflected_comparison_used(self):
+        class AlwaysEqual:
+            def __eq__(self, other):
+                return True
+        assert Bits('0x0') == AlwaysEqual()
+        assert not (Bits('0x0') != AlwaysEqual())
+
+
+class TestFoldLogicalOps:
+
+    def test_all_and(self):
+        assert Bits.all_and(['0b1110', '0b0111', '0b1111']) == '0b0110'
+        assert Bits.all_and([Bits('0xab')]) == '0xab'
+
+    def test_all_or(self):
+        assert Bits.all_or(['0b1000', '0b0010']) == '0b1010'
+        assert Bits.all_or(Bits.from_positions([i], 8) for i in range(8)) == '0xff'
+
+    def test_source_unchanged(self):
+        a = Bits('0b1100')
+        assert Bits.all_and([a, '0b0110']) == '0b0100'
+        assert Bits.all_or([a, '0b0011']) == '0b1111'
+        assert a == '0b1100'
+
+    def test_errors(self):
+        with pytest.raises(ValueError):
+            Bits.all_and([])
+        with pytest.raises(ValueError):
+            Bits.all_or(iter([]))
+        with pytest.raises(ValueError):
+            Bits.all_and(['0b11', '0b11', '0b1'])
+
+
+class TestStrictStrings:
+
+    def test_lenient_by_default(self):
+        assert Bits.from_string('0x123') == '0b000100100011'
+        assert Bits.from_string('0b_1__0_') == '0b10'
+
+    @pytest.mark.parametrize('s', ['0x1234', '0x12_34', '0b1_0_1', '0xab, 0b1', 'u8=3, 0xff', '0o7_7'])
+    def test_strict_valid(self, s):
+        assert Bits.from_string(s, strict=True) == Bits.from_string(s)
+
+    @pytest.mark.parametrize('s', ['0x123', '0xab, 0x1', '0b_10', '0b10_', '0b1__0', '0x12__34', '0o_7'])
+    def test_strict_invalid(self, s):
+        with pytest.raises(ValueError):
+            Bits.from_string(s, strict=True)
+
+
+class TestConcat:
+
+    def test_heterogeneous(self):
+        a = Bits('0x1')
+        b = a.concat('0x2', b'\x34', Bits('0b1'), [1, 0, 0], bytearray(b'\xff'))
+        assert b == '0x1234, 0b1100, 0xff'
+        assert b == a + '0x2' + b'\x34' + Bits('0b1') + [1, 0, 0] + bytearray(b'\xff')
+        assert a == '0x1'
+
+    def test_no_others(self):
+        a = Bits('0xab')
+        assert a.concat() == a
+        assert Bits().concat(Bits(), '') == Bits()
@@ -405,8 +405,7 @@ class TestAdding:
         s = Bits('0b11111')
         with pytest.raises(ValueError):
             _ = s.overwrite(Bits('0b1'), -10)
-        with pytest.raises(ValueError):
-            _ = s.overwrite(Bits('0b1'), 6)
+        assert s.overwrite(Bits('0b1'), 6).bin == '1111101'
         s = s.overwrite('bin=0', 5)
         assert s.bin == '111110'
         s = s.overwrite(Bits('0x00'), 1)
@@ -755,15 +754,13 @@ class TestManyDifferentThings:
         a = Bits('0b00100')
         with pytest.raises(ValueError):
             _ = a.find('0b1', bytealigned=False, start=-100)
-        with pytest.raises(ValueError):
-            _ = a.find('0b1', end=6)
+        assert a.find('0b1', end=6) == 2
         with pytest.raises(ValueError):
             _ = a.find('0b1', start=4, end=3)
         b = Bits('0x0011223344')
         with pytest.raises(ValueError):
             _ = b.find('0x22', bytealigned=True, start=-100)
-        with pytest.raises(ValueError):
-            _ = b.find('0x22', end=41, bytealigned=True)
+        assert b.find('0x22', end=41, bytealigned=True) == 16
 
     def test_find_all(self):
         a = Bits('0b11111')
@@ -1236,8 +1233,7 @@ class TestMoreMisc:
 
     def test_ror_errors(self):
         a = Bits()
-        with pytest.raises(ValueError):
-            _ = a.ror(0)
+        assert a.ror(0) == Bits()
         a += '0b001'
         with pytest.raises(ValueError):
             _ = a.ror(-1)
@@ -1258,8 +1254,7 @@ class TestMoreMisc:
 
     def test_rol_errors(self):
         a = Bits()
-        with pytest.raises(ValueError):
-            a.rol(0)
+        assert a.rol(0) == Bits()
         a += '0b001'
         with pytest.raises(ValueError):
             a.rol(-1)
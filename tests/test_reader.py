@@ -0,0 +1,62 @@
+#!/usr/bin/env python
+import pytest
+from bitformat import Bits, Reader
+
+
+class TestReader:
+
+    def test_read(self):
+        r = Reader(Bits('0x12f'))
+        assert r.read(4) == '0x1'
+        assert r.tell() == 4
+        assert r.read(8) == '0x2f'
+        assert r.tell() == 12
+        assert r.read(0) == Bits()
+
+    def test_peek(self):
+        r = Reader('0b1100')
+        assert r.peek(2) == '0b11'
+        assert r.tell() == 0
+        assert r.read(3) == '0b110'
+        assert r.peek(1) == '0b0'
+
+    def test_seek(self):
+        r = Reader(Bits('0xabcd'), pos=8)
+        assert r.read(8) == '0xcd'
+        r.seek(4)
+        assert r.read(4) == '0xb'
+        r.seek(-4)
+        assert r.tell() == 12
+        r.seek(16)
+        assert r.read(0) == Bits()
+
+    def test_bits_unchanged(self):
+        a = Bits('0xabcd')
+        r = Reader(a)
+        r.read(8)
+        assert r.bits is a
+        assert a == '0xabcd'
+
+    def test_read_past_end(self):
+        r = Reader(Bits('0xab'))
+        r.read(6)
+        with pytest.raises(ValueError, match='bits were available'):
+            r.read(3)
+        with pytest.raises(ValueError, match='bits were available'):
+            r.peek(3)
+        assert r.tell() == 6
+
+    def test_errors(self):
+        r = Reader(Bits('0xab'))
+        with pytest.raises(ValueError):
+            r.read(-1)
+        with pytest.raises(ValueError):
+            r.seek(9)
+        with pytest.raises(ValueError):
+            r.seek(-9)
+        with pytest.raises(ValueError):
+            Reader(Bits('0xab'), pos=10)
+
+    def test_repr(self):
+        r = Reader(Bits('0xab'), pos=4)
+        assert repr(r) == "Reader(Bits('0xab'), pos=4)"